@@ -0,0 +1,18 @@
+use fortnite_api::assets::AssetDownloader;
+
+#[tokio::main]
+async fn main() {
+    let http_client = reqwest::Client::new();
+
+    let shop = fortnite_api::get_shop_br_v2(&http_client, None).await;
+    assert!(shop.is_ok());
+
+    let downloader = AssetDownloader::new(http_client, "assets").with_concurrency(16);
+    let result = downloader.download(&shop.unwrap()).await;
+    println!("Result: {result:#?}");
+    assert!(result.is_ok());
+
+    let result = downloader.verify().await;
+    println!("Result: {result:#?}");
+    assert!(result.is_ok());
+}
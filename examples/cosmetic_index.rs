@@ -0,0 +1,33 @@
+use fortnite_api::cosmetics::{CosmeticFilter, CosmeticIndex, SearchOptions};
+
+#[tokio::main]
+async fn main() {
+    let http_client = reqwest::Client::new();
+
+    let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await;
+    assert!(cosmetics.is_ok());
+    let index = CosmeticIndex::new(cosmetics.unwrap());
+
+    let filter = CosmeticFilter::Type("outfit".into())
+        .and(CosmeticFilter::Introduced {
+            chapter: "1".into(),
+            season: Some("1".into()),
+        })
+        .and(!CosmeticFilter::HasVariants);
+    let result = index.query(&filter);
+    println!(
+        "Result: {:#?}",
+        result.iter().map(|c| &c.name).collect::<Vec<_>>()
+    );
+    assert!(!result.is_empty());
+
+    let result = index.search("renegde raidr", &SearchOptions::default());
+    println!(
+        "Result: {:#?}",
+        result
+            .iter()
+            .map(|m| (&m.cosmetic.name, m.score))
+            .collect::<Vec<_>>()
+    );
+    assert!(!result.is_empty());
+}
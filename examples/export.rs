@@ -0,0 +1,33 @@
+use fortnite_api::export::{
+    write_csv, write_ndjson, CosmeticColumn, ExportRecord, ShopEntryColumn,
+};
+use fortnite_api::response_types::cosmetics::CosmeticV2;
+
+#[tokio::main]
+async fn main() {
+    let http_client = reqwest::Client::new();
+
+    let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await;
+    assert!(cosmetics.is_ok());
+    let file = std::fs::File::create("cosmetics.csv").unwrap();
+    let result = write_csv(file, &cosmetics.unwrap(), CosmeticV2::default_columns());
+    assert!(result.is_ok());
+
+    let shop = fortnite_api::get_shop_br_v2(&http_client, None).await;
+    assert!(shop.is_ok());
+    let columns = [
+        ShopEntryColumn::OfferId,
+        ShopEntryColumn::ItemNames,
+        ShopEntryColumn::FinalPrice,
+    ];
+    let file = std::fs::File::create("shop.ndjson").unwrap();
+    let result = write_ndjson(file, &shop.unwrap().featured.entries, &columns);
+    assert!(result.is_ok());
+
+    let columns = "id,name,rarity"
+        .split(',')
+        .map(|column| column.parse::<CosmeticColumn>())
+        .collect::<Result<Vec<_>, _>>();
+    println!("Result: {columns:#?}");
+    assert!(columns.is_ok());
+}
@@ -0,0 +1,55 @@
+use fortnite_api::render::map::{
+    render_map, MapAnnotations, MapHeatPoint, MapMarker, MapPath, MapRenderOptions,
+};
+use fortnite_api::render::{Color, FontArc};
+use fortnite_api::response_types::map::MapPoiLocation;
+
+#[tokio::main]
+async fn main() {
+    let http_client = reqwest::Client::new();
+
+    let map = fortnite_api::get_map_v1(&http_client, None).await.unwrap();
+    let blank = http_client
+        .get(&map.images.blank)
+        .send()
+        .await
+        .unwrap()
+        .bytes()
+        .await
+        .unwrap();
+
+    let font = std::env::var("FONT_PATH")
+        .ok()
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|data| FontArc::try_from_vec(data).ok());
+    let options = MapRenderOptions {
+        font,
+        ..MapRenderOptions::default()
+    };
+
+    let location = |x, y| MapPoiLocation { x, y, z: 0.0 };
+    let annotations = MapAnnotations {
+        markers: vec![MapMarker {
+            location: location(0.0, 0.0),
+            label: Some("Center".to_string()),
+            color: Color::from_rgba8(255, 0, 0, 255),
+            radius: 10.0,
+        }],
+        paths: vec![MapPath {
+            points: vec![
+                location(-100_000.0, -100_000.0),
+                location(100_000.0, 100_000.0),
+            ],
+            color: Color::from_rgba8(0, 128, 255, 255),
+            width: 6.0,
+        }],
+        heat_points: vec![MapHeatPoint {
+            location: location(20_000.0, -30_000.0),
+            weight: 0.8,
+        }],
+    };
+
+    let result = render_map(&map, &blank, &annotations, &options);
+    assert!(result.is_ok());
+    std::fs::write("map.png", result.unwrap()).unwrap();
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use fortnite_api::assets::ImageAssets;
+use fortnite_api::render::shop::{render_shop, ShopRenderOptions};
+use fortnite_api::render::FontArc;
+
+#[tokio::main]
+async fn main() {
+    let http_client = reqwest::Client::new();
+
+    let shop = fortnite_api::get_shop_br_v2(&http_client, None)
+        .await
+        .unwrap();
+
+    let mut images = HashMap::new();
+    for asset in shop.image_assets() {
+        if images.contains_key(&asset.url) {
+            continue;
+        }
+        let response = http_client.get(&asset.url).send().await.unwrap();
+        images.insert(asset.url, response.bytes().await.unwrap().to_vec());
+    }
+
+    let font = std::env::var("FONT_PATH")
+        .ok()
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|data| FontArc::try_from_vec(data).ok());
+    let options = ShopRenderOptions {
+        font,
+        ..ShopRenderOptions::default()
+    };
+
+    let result = render_shop(&shop, &images, &options);
+    assert!(result.is_ok());
+    std::fs::write("shop.png", result.unwrap()).unwrap();
+}
@@ -0,0 +1,26 @@
+use fortnite_api::render::stats::{render_stats_card, StatsCardOptions, StatsCardTheme};
+use fortnite_api::render::FontArc;
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+    let http_client = reqwest::Client::new();
+    let api_key = std::env::var("FORTNITE_API_KEY")
+        .expect("Please set the FORTNITE_API_KEY environment variable");
+
+    let stats = fortnite_api::get_stats_v2(&http_client, api_key, "Test", None, None, None)
+        .await
+        .unwrap();
+
+    let font_path =
+        std::env::var("FONT_PATH").expect("Please set the FONT_PATH environment variable");
+    let font = FontArc::try_from_vec(std::fs::read(font_path).unwrap()).unwrap();
+    let options = StatsCardOptions {
+        theme: StatsCardTheme::dark().with_font(font),
+        ..StatsCardOptions::default()
+    };
+
+    let result = render_stats_card(&stats, &options);
+    assert!(result.is_ok());
+    std::fs::write("stats.png", result.unwrap()).unwrap();
+}
@@ -0,0 +1,17 @@
+use fortnite_api::storage::SnapshotStore;
+
+#[tokio::main]
+async fn main() {
+    let http_client = reqwest::Client::new();
+    let mut store = SnapshotStore::open("snapshots.db").unwrap();
+
+    let shop = fortnite_api::get_shop_br_v2(&http_client, None).await;
+    assert!(shop.is_ok());
+    let id = store.insert_if_changed(&shop.unwrap(), chrono::Utc::now());
+    println!("Result: {id:#?}");
+    assert!(id.is_ok());
+
+    let shops = store.shops_containing("CID_029_Athena_Commando_F_Halloween");
+    assert!(shops.is_ok());
+    println!("Found in {} stored shops", shops.unwrap().len());
+}
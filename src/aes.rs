@@ -0,0 +1,272 @@
+//! Conversion and export of AES keys, and the cosmetics they decrypt.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde_json::json;
+
+use crate::response_types::aes::{AesKey, AesKeyError, AesKeyFormat, AesV2, DynamicKey, PakGuid};
+use crate::response_types::cosmetics::CosmeticV2;
+
+/// The GUID tools use for the main key of the game's paks.
+pub const MAIN_KEY_GUID: PakGuid = PakGuid::ZERO;
+
+pub fn convert_key(key: &str, format: AesKeyFormat) -> Result<String, AesKeyError> {
+    //! Convert a hex or base64 key to `format`.
+    //!
+    //! ## Example
+    //!
+    //! ```rust
+    //! use fortnite_api::aes::convert_key;
+    //! use fortnite_api::response_types::aes::AesKeyFormat;
+    //!
+    //! let hex = "0x".to_string() + &"00".repeat(31) + "FF";
+    //! let base64 = convert_key(&hex, AesKeyFormat::Base64).unwrap();
+    //! assert_eq!(base64, "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8=");
+    //! assert_eq!(convert_key(&base64, AesKeyFormat::Hex).unwrap(), hex);
+    //! ```
+    key.parse::<AesKey>().map(|key| key.to_format(format))
+}
+
+pub fn convert_keys(aes: &AesV2, format: AesKeyFormat) -> AesV2 {
+    //! Convert the main key and all dynamic keys to `format`, instead of fetching the keys
+    //! again with another `key_format`.
+    AesV2 {
+        build: aes.build.clone(),
+        main_key: aes.main_key.with_format(format),
+        dynamic_keys: aes
+            .dynamic_keys
+            .iter()
+            .map(|dynamic_key| DynamicKey {
+                key: dynamic_key.key.with_format(format),
+                ..dynamic_key.clone()
+            })
+            .collect(),
+        updated: aes.updated,
+    }
+}
+
+/// Key file layouts of Unreal Engine pak and IoStore extraction tools.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AesExportFormat {
+    /// The JSON layout of FModel's AES settings:
+    /// `{"version", "mainKey", "dynamicKeys": [{"name", "guid", "key"}]}`.
+    FModel,
+    /// A JSON object mapping pak GUIDs to keys, with the main key under
+    /// [`MAIN_KEY_GUID`], as submitted to CUE4Parse based tools.
+    GuidMap,
+    /// One key per line, main key first, as read by UModel's `-aes=@file`. Keys are always
+    /// written as `0x` prefixed hex, which UModel expects.
+    KeyList,
+}
+
+impl AesExportFormat {
+    pub const ALL: [AesExportFormat; 3] = [
+        AesExportFormat::FModel,
+        AesExportFormat::GuidMap,
+        AesExportFormat::KeyList,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AesExportFormat::FModel => "fmodel",
+            AesExportFormat::GuidMap => "guid_map",
+            AesExportFormat::KeyList => "key_list",
+        }
+    }
+}
+
+impl FromStr for AesExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AesExportFormat::ALL
+            .into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown AES export format: {s}"))
+    }
+}
+
+pub fn export_keys(aes: &AesV2, format: AesExportFormat, key_format: AesKeyFormat) -> String {
+    //! Export the keys of `aes` for an extraction tool.
+    //!
+    //! ## Parameters
+    //!
+    //! - `aes`: The AES keys, in any key format.
+    //! - `format`: The layout of the exported file.
+    //! - `key_format`: Whether keys are written as hex or base64. Ignored by
+    //!   [`AesExportFormat::KeyList`], which is always hex.
+    //!
+    //! ## Returns
+    //!
+    //! The contents of the key file.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::aes::{export_keys, AesExportFormat};
+    //! use fortnite_api::response_types::aes::AesKeyFormat;
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!     let aes = fortnite_api::get_aes_keys_v2(&http_client, None).await.unwrap();
+    //!
+    //!     let keys = export_keys(&aes, AesExportFormat::GuidMap, AesKeyFormat::Hex);
+    //!     std::fs::write("keys.json", keys).unwrap();
+    //! }
+    //! ```
+    let aes = convert_keys(aes, key_format);
+    match format {
+        AesExportFormat::FModel => {
+            let dynamic_keys = aes
+                .dynamic_keys
+                .iter()
+                .map(|dynamic_key| {
+                    json!({
+                        "name": dynamic_key.pak_filename,
+                        "guid": dynamic_key.pak_guid,
+                        "key": dynamic_key.key,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let value = json!({
+                "version": aes.build,
+                "mainKey": aes.main_key,
+                "dynamicKeys": dynamic_keys,
+            });
+            serde_json::to_string_pretty(&value).unwrap_or_default()
+        }
+        AesExportFormat::GuidMap => {
+            let mut keys = BTreeMap::new();
+            keys.insert(MAIN_KEY_GUID.to_string(), aes.main_key.to_string());
+            for dynamic_key in &aes.dynamic_keys {
+                keys.insert(
+                    dynamic_key.pak_guid.to_string(),
+                    dynamic_key.key.to_string(),
+                );
+            }
+            serde_json::to_string_pretty(&keys).unwrap_or_default()
+        }
+        AesExportFormat::KeyList => {
+            let mut keys = vec![aes.main_key];
+            for dynamic_key in &aes.dynamic_keys {
+                if !keys.contains(&dynamic_key.key) {
+                    keys.push(dynamic_key.key);
+                }
+            }
+            keys.iter()
+                .map(|key| format!("{}\n", key.to_hex()))
+                .collect()
+        }
+    }
+}
+
+pub fn pak_id(pak_filename: &str) -> Option<&str> {
+    //! The ID of a dynamic pak, as referenced by `CosmeticV2::dynamic_pak_id`, from its file
+    //! name, e.g. `1001` for `pakchunk1001-WindowsClient.pak`.
+    let rest = pak_filename
+        .rsplit(['/', '\\'])
+        .next()?
+        .strip_prefix("pakchunk")?;
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest.get(..end).filter(|id| !id.is_empty())
+}
+
+/// A cosmetic stored in a dynamic pak, with the key of the pak if it is known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CosmeticPakKey<'a> {
+    pub cosmetic: &'a CosmeticV2,
+    pub pak_id: &'a str,
+    pub key: Option<&'a DynamicKey>,
+}
+
+impl CosmeticPakKey<'_> {
+    pub fn is_decryptable(&self) -> bool {
+        self.key.is_some()
+    }
+}
+
+/// Which cosmetics in dynamic paks can be decrypted with the known keys.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DynamicKeyReport<'a> {
+    /// Every cosmetic with a `dynamic_pak_id`, in the given order.
+    pub cosmetics: Vec<CosmeticPakKey<'a>>,
+    /// Keys of paks no given cosmetic is stored in.
+    pub unmatched_keys: Vec<&'a DynamicKey>,
+}
+
+impl<'a> DynamicKeyReport<'a> {
+    pub fn decryptable(&self) -> impl Iterator<Item = &CosmeticPakKey<'a>> {
+        self.cosmetics.iter().filter(|entry| entry.is_decryptable())
+    }
+
+    pub fn encrypted(&self) -> impl Iterator<Item = &CosmeticPakKey<'a>> {
+        //! Cosmetics whose pak key is not known yet.
+        self.cosmetics
+            .iter()
+            .filter(|entry| !entry.is_decryptable())
+    }
+
+    pub fn by_pak(&self) -> BTreeMap<&'a str, Vec<&'a CosmeticV2>> {
+        //! The cosmetics of each pak.
+        let mut paks: BTreeMap<&str, Vec<&CosmeticV2>> = BTreeMap::new();
+        for entry in &self.cosmetics {
+            paks.entry(entry.pak_id).or_default().push(entry.cosmetic);
+        }
+        paks
+    }
+}
+
+pub fn map_dynamic_keys<'a>(
+    cosmetics: impl IntoIterator<Item = &'a CosmeticV2>,
+    aes: &'a AesV2,
+) -> DynamicKeyReport<'a> {
+    //! Match cosmetics in dynamic paks to the keys of `aes` by their `dynamic_pak_id`.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!     let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await.unwrap();
+    //!     let aes = fortnite_api::get_aes_keys_v2(&http_client, None).await.unwrap();
+    //!
+    //!     let report = fortnite_api::aes::map_dynamic_keys(&cosmetics, &aes);
+    //!     for entry in report.encrypted() {
+    //!         println!("{} is still encrypted in pak {}", entry.cosmetic.name, entry.pak_id);
+    //!     }
+    //! }
+    //! ```
+    let keys = aes
+        .dynamic_keys
+        .iter()
+        .filter_map(|key| pak_id(&key.pak_filename).map(|id| (id, key)))
+        .collect::<BTreeMap<_, _>>();
+    let cosmetics = cosmetics
+        .into_iter()
+        .filter_map(|cosmetic| {
+            let pak_id = cosmetic.dynamic_pak_id.as_deref()?.trim();
+            Some(CosmeticPakKey {
+                cosmetic,
+                pak_id,
+                key: keys.get(pak_id).copied(),
+            })
+        })
+        .collect::<Vec<_>>();
+    let unmatched_keys = aes
+        .dynamic_keys
+        .iter()
+        .filter(|key| {
+            pak_id(&key.pak_filename)
+                .is_none_or(|id| cosmetics.iter().all(|entry| entry.pak_id != id))
+        })
+        .collect();
+    DynamicKeyReport {
+        cosmetics,
+        unmatched_keys,
+    }
+}
@@ -0,0 +1,321 @@
+use crate::assets::{AssetRole, ImageAsset};
+use crate::response_types::banners::BannerV1;
+use crate::response_types::cosmetics::{CosmeticV2, CosmeticsNewV2};
+use crate::response_types::map::MapV1;
+use crate::response_types::news::{News, NewsContent, NewsV2};
+use crate::response_types::playlists::PlaylistV1;
+use crate::response_types::shop::{ShopFeaturedEntry, ShopV2};
+use crate::response_types::stats::StatsV2;
+
+/// Types which reference images that can be downloaded.
+pub trait ImageAssets {
+    /// Append all image assets of `self` to `assets`.
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>);
+
+    fn image_assets(&self) -> Vec<ImageAsset> {
+        //! List all image assets of `self`.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::assets::{AssetRole, ImageAssets};
+        //! use fortnite_api::response_types::map::{MapImages, MapV1};
+        //!
+        //! let map = MapV1 {
+        //!     images: MapImages {
+        //!         blank: "https://fortnite-api.com/images/map.png".to_string(),
+        //!         pois: "https://fortnite-api.com/images/map_en.png".to_string(),
+        //!     },
+        //!     pois: vec![],
+        //! };
+        //!
+        //! let assets = map.image_assets();
+        //! assert_eq!(assets.len(), 2);
+        //! assert_eq!(assets[0].role, AssetRole::MapBlank);
+        //! assert_eq!(assets[0].path, std::path::Path::new("map/blank.png"));
+        //! ```
+        let mut assets = Vec::new();
+        self.collect_image_assets(&mut assets);
+        assets
+    }
+}
+
+impl<T: ImageAssets> ImageAssets for Vec<T> {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        for item in self {
+            item.collect_image_assets(assets);
+        }
+    }
+}
+
+impl<T: ImageAssets> ImageAssets for Option<T> {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        if let Some(item) = self {
+            item.collect_image_assets(assets);
+        }
+    }
+}
+
+impl ImageAssets for CosmeticV2 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        let directory = ["cosmetics", self.id.as_str()];
+        let images = &self.images;
+        let optional = [
+            (
+                &images.small_icon,
+                AssetRole::CosmeticSmallIcon,
+                "small_icon",
+            ),
+            (&images.icon, AssetRole::CosmeticIcon, "icon"),
+            (&images.featured, AssetRole::CosmeticFeatured, "featured"),
+        ];
+        for (url, role, name) in optional {
+            if let Some(url) = url {
+                assets.push(ImageAsset::new(url, role, &directory, name));
+            }
+        }
+        if let Some(lego) = &images.lego {
+            assets.push(ImageAsset::new(
+                &lego.small,
+                AssetRole::CosmeticLegoSmall,
+                &directory,
+                "lego_small",
+            ));
+            assets.push(ImageAsset::new(
+                &lego.large,
+                AssetRole::CosmeticLegoLarge,
+                &directory,
+                "lego_large",
+            ));
+            if let Some(wide) = &lego.wide {
+                assets.push(ImageAsset::new(
+                    wide,
+                    AssetRole::CosmeticLegoWide,
+                    &directory,
+                    "lego_wide",
+                ));
+            }
+        }
+        if let Some(other) = &images.other {
+            if let Some(background) = &other.background {
+                assets.push(ImageAsset::new(
+                    background,
+                    AssetRole::CosmeticBackground,
+                    &directory,
+                    "background",
+                ));
+            }
+            if let Some(coverart) = &other.coverart {
+                assets.push(ImageAsset::new(
+                    coverart,
+                    AssetRole::CosmeticCoverArt,
+                    &directory,
+                    "coverart",
+                ));
+            }
+        }
+        if let Some(image) = self
+            .series
+            .as_ref()
+            .and_then(|series| series.image.as_ref())
+        {
+            assets.push(ImageAsset::new(
+                image,
+                AssetRole::CosmeticSeries,
+                &directory,
+                "series",
+            ));
+        }
+        for variant in self.variants.iter().flatten() {
+            for option in &variant.options {
+                assets.push(ImageAsset::new(
+                    &option.image,
+                    AssetRole::CosmeticVariant,
+                    &["cosmetics", self.id.as_str(), "variants", &variant.channel],
+                    &option.tag,
+                ));
+            }
+        }
+    }
+}
+
+impl ImageAssets for CosmeticsNewV2 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        self.items.collect_image_assets(assets);
+    }
+}
+
+impl ImageAssets for BannerV1 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        let directory = ["banners", self.id.as_str()];
+        assets.push(ImageAsset::new(
+            &self.images.small_icon,
+            AssetRole::BannerSmallIcon,
+            &directory,
+            "small_icon",
+        ));
+        assets.push(ImageAsset::new(
+            &self.images.icon,
+            AssetRole::BannerIcon,
+            &directory,
+            "icon",
+        ));
+    }
+}
+
+impl ImageAssets for MapV1 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        assets.push(ImageAsset::new(
+            &self.images.blank,
+            AssetRole::MapBlank,
+            &["map"],
+            "blank",
+        ));
+        assets.push(ImageAsset::new(
+            &self.images.pois,
+            AssetRole::MapPois,
+            &["map"],
+            "pois",
+        ));
+    }
+}
+
+impl ImageAssets for NewsV2 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        self.br.collect_image_assets(assets);
+        self.stw.collect_image_assets(assets);
+        self.creative.collect_image_assets(assets);
+    }
+}
+
+impl ImageAssets for News {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        if let News::NewsContent(content) = self {
+            content.collect_image_assets(assets);
+        }
+    }
+}
+
+impl ImageAssets for NewsContent {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        let directory = ["news", self.hash.as_str()];
+        if let Some(image) = &self.image {
+            assets.push(ImageAsset::new(
+                image,
+                AssetRole::NewsImage,
+                &directory,
+                "image",
+            ));
+        }
+        for motd in self.motds.iter().flatten() {
+            let directory = ["news", self.hash.as_str(), "motds", motd.id.as_str()];
+            assets.push(ImageAsset::new(
+                &motd.image,
+                AssetRole::NewsMotdImage,
+                &directory,
+                "image",
+            ));
+            assets.push(ImageAsset::new(
+                &motd.tile_image,
+                AssetRole::NewsMotdTileImage,
+                &directory,
+                "tile_image",
+            ));
+        }
+        for (index, message) in self.messages.iter().flatten().enumerate() {
+            assets.push(ImageAsset::new(
+                &message.image,
+                AssetRole::NewsMessageImage,
+                &["news", self.hash.as_str(), "messages"],
+                &index.to_string(),
+            ));
+        }
+    }
+}
+
+impl ImageAssets for PlaylistV1 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        let directory = ["playlists", self.id.as_str()];
+        if let Some(showcase) = &self.images.showcase {
+            assets.push(ImageAsset::new(
+                showcase,
+                AssetRole::PlaylistShowcase,
+                &directory,
+                "showcase",
+            ));
+        }
+        if let Some(mission_icon) = &self.images.mission_icon {
+            assets.push(ImageAsset::new(
+                mission_icon,
+                AssetRole::PlaylistMissionIcon,
+                &directory,
+                "mission_icon",
+            ));
+        }
+    }
+}
+
+impl ImageAssets for ShopV2 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        assets.push(ImageAsset::new(
+            &self.vbuck_icon,
+            AssetRole::ShopVbuckIcon,
+            &["shop"],
+            "vbuck_icon",
+        ));
+        self.featured.entries.collect_image_assets(assets);
+    }
+}
+
+impl ImageAssets for ShopFeaturedEntry {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        let directory = ["shop", self.offer_id.as_str()];
+        if let Some(bundle) = &self.bundle {
+            assets.push(ImageAsset::new(
+                &bundle.image,
+                AssetRole::ShopBundleImage,
+                &directory,
+                "bundle",
+            ));
+        }
+        let material_instances = self
+            .new_display_asset
+            .iter()
+            .flat_map(|display_asset| &display_asset.material_instances);
+        for material_instance in material_instances {
+            let directory = [
+                "shop",
+                self.offer_id.as_str(),
+                material_instance.id.as_str(),
+            ];
+            assets.push(ImageAsset::new(
+                &material_instance.images.offer_image,
+                AssetRole::ShopOfferImage,
+                &directory,
+                "offer_image",
+            ));
+            if let Some(background) = &material_instance.images.background {
+                assets.push(ImageAsset::new(
+                    background,
+                    AssetRole::ShopOfferBackground,
+                    &directory,
+                    "background",
+                ));
+            }
+        }
+        self.items.collect_image_assets(assets);
+    }
+}
+
+impl ImageAssets for StatsV2 {
+    fn collect_image_assets(&self, assets: &mut Vec<ImageAsset>) {
+        if let Some(image) = &self.image {
+            assets.push(ImageAsset::new(
+                image.as_str(),
+                AssetRole::StatsImage,
+                &["stats"],
+                &self.account.id,
+            ));
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+use futures_util::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::assets::{ImageAsset, ImageAssets};
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// The number of downloaded files after which the manifest is saved, so an interrupted run
+/// only repeats the downloads since the last save.
+const MANIFEST_SAVE_INTERVAL: usize = 32;
+
+#[derive(Debug)]
+pub enum AssetError {
+    Http(reqwest::Error),
+    Io(std::io::Error),
+    Manifest(serde_json::Error),
+}
+
+impl Display for AssetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetError::Http(error) => write!(f, "failed to download asset: {error}"),
+            AssetError::Io(error) => write!(f, "failed to write asset: {error}"),
+            AssetError::Manifest(error) => write!(f, "invalid asset manifest: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AssetError::Http(error) => Some(error),
+            AssetError::Io(error) => Some(error),
+            AssetError::Manifest(error) => Some(error),
+        }
+    }
+}
+
+impl From<reqwest::Error> for AssetError {
+    fn from(error: reqwest::Error) -> Self {
+        AssetError::Http(error)
+    }
+}
+
+impl From<std::io::Error> for AssetError {
+    fn from(error: std::io::Error) -> Self {
+        AssetError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for AssetError {
+    fn from(error: serde_json::Error) -> Self {
+        AssetError::Manifest(error)
+    }
+}
+
+/// Record of all downloaded files, stored as `manifest.json` in the download directory.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetManifest {
+    /// Entries keyed by the path relative to the download directory, using `/` as separator.
+    pub files: BTreeMap<String, AssetManifestEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetManifestEntry {
+    pub url: String,
+    /// Lowercase hex encoded SHA-256 of the file contents.
+    pub sha256: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct DownloadReport {
+    /// Assets which were downloaded by this run.
+    pub downloaded: Vec<ImageAsset>,
+    /// Assets which were already present with a matching hash.
+    pub skipped: Vec<ImageAsset>,
+    /// Assets which could not be downloaded, grouped by their shared URL.
+    pub failed: Vec<(Vec<ImageAsset>, AssetError)>,
+}
+
+/// Downloads [`ImageAsset`]s into a directory.
+///
+/// Assets are deduplicated by URL and path, downloaded with bounded concurrency and written
+/// atomically. Files which are already listed in the manifest with a matching SHA-256 are
+/// skipped, so an interrupted download can simply be started again.
+#[derive(Debug, Clone)]
+pub struct AssetDownloader {
+    http_client: reqwest::Client,
+    root: PathBuf,
+    concurrency: usize,
+}
+
+impl AssetDownloader {
+    pub fn new(http_client: reqwest::Client, root: impl Into<PathBuf>) -> Self {
+        AssetDownloader {
+            http_client,
+            root: root.into(),
+            concurrency: 8,
+        }
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        //! Set the maximum number of simultaneous downloads. Defaults to 8.
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub async fn load_manifest(&self) -> Result<AssetManifest, AssetError> {
+        //! Load the manifest of the download directory, or an empty one if there is none yet.
+        match tokio::fs::read(self.root.join(MANIFEST_FILE)).await {
+            Ok(data) => Ok(serde_json::from_slice(&data)?),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                Ok(AssetManifest::default())
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn save_manifest(&self, manifest: &AssetManifest) -> Result<(), AssetError> {
+        let data = serde_json::to_vec_pretty(manifest)?;
+        write_atomic(&self.root.join(MANIFEST_FILE), &data).await?;
+        Ok(())
+    }
+
+    pub async fn download(
+        &self,
+        response: &impl ImageAssets,
+    ) -> Result<DownloadReport, AssetError> {
+        //! Download all image assets of a response.
+        //!
+        //! ## Parameters
+        //!
+        //! - `response`: Any response implementing [`ImageAssets`].
+        //!
+        //! ## Returns
+        //!
+        //! A report of downloaded, skipped and failed assets. Only failures to read or write
+        //! the manifest abort the whole download.
+        //!
+        //! ## Example
+        //!
+        //! ```rust no_run
+        //! use fortnite_api::assets::AssetDownloader;
+        //!
+        //! #[tokio::main]
+        //! async fn main() {
+        //!     let http_client = reqwest::Client::new();
+        //!
+        //!     let banners = fortnite_api::get_banners_v1(&http_client, None).await.unwrap();
+        //!     let downloader = AssetDownloader::new(http_client, "assets").with_concurrency(16);
+        //!     let report = downloader.download(&banners).await.unwrap();
+        //!     assert!(report.failed.is_empty());
+        //! }
+        //! ```
+        self.download_assets(response.image_assets()).await
+    }
+
+    pub async fn download_assets(
+        &self,
+        assets: Vec<ImageAsset>,
+    ) -> Result<DownloadReport, AssetError> {
+        //! Download a list of image assets. See [`AssetDownloader::download`].
+        let mut manifest = self.load_manifest().await?;
+        let mut report = DownloadReport::default();
+
+        let mut seen_paths = std::collections::HashSet::new();
+        let mut jobs: HashMap<String, Vec<ImageAsset>> = HashMap::new();
+        let mut job_order = Vec::new();
+        for asset in assets {
+            if !seen_paths.insert(asset.path.clone()) {
+                continue;
+            }
+            if self.is_complete(&manifest, &asset).await {
+                report.skipped.push(asset);
+                continue;
+            }
+            if !jobs.contains_key(&asset.url) {
+                job_order.push(asset.url.clone());
+            }
+            jobs.entry(asset.url.clone()).or_default().push(asset);
+        }
+
+        let mut results = stream::iter(job_order.into_iter().filter_map(|url| jobs.remove(&url)))
+            .map(|assets| async move {
+                let result = self.fetch(&assets).await;
+                (assets, result)
+            })
+            .buffer_unordered(self.concurrency);
+
+        let mut unsaved = 0;
+        while let Some((assets, result)) = results.next().await {
+            match result {
+                Ok(entry) => {
+                    for asset in assets {
+                        manifest
+                            .files
+                            .insert(manifest_key(&asset.path), entry.clone());
+                        report.downloaded.push(asset);
+                        unsaved += 1;
+                    }
+                }
+                Err(error) => report.failed.push((assets, error)),
+            }
+            if unsaved >= MANIFEST_SAVE_INTERVAL {
+                self.save_manifest(&manifest).await?;
+                unsaved = 0;
+            }
+        }
+
+        self.save_manifest(&manifest).await?;
+        Ok(report)
+    }
+
+    pub async fn verify(&self) -> Result<Vec<PathBuf>, AssetError> {
+        //! Check every file of the manifest against its recorded SHA-256.
+        //!
+        //! ## Returns
+        //!
+        //! The relative paths of all files which are missing or whose contents changed.
+        let manifest = self.load_manifest().await?;
+        let mut changed = Vec::new();
+        for (path, entry) in &manifest.files {
+            if hash_file(&self.root.join(path)).await.ok().as_deref() != Some(entry.sha256.as_str())
+            {
+                changed.push(PathBuf::from(path));
+            }
+        }
+        Ok(changed)
+    }
+
+    async fn is_complete(&self, manifest: &AssetManifest, asset: &ImageAsset) -> bool {
+        // Files without a manifest entry may be leftovers or foreign files, so they are
+        // downloaded again and replaced.
+        let Some(entry) = manifest.files.get(&manifest_key(&asset.path)) else {
+            return false;
+        };
+        entry.url == asset.url
+            && hash_file(&self.root.join(&asset.path))
+                .await
+                .ok()
+                .as_deref()
+                == Some(entry.sha256.as_str())
+    }
+
+    async fn fetch(&self, assets: &[ImageAsset]) -> Result<AssetManifestEntry, AssetError> {
+        let Some(url) = assets.first().map(|asset| asset.url.clone()) else {
+            return Err(std::io::Error::other("no assets to download").into());
+        };
+        let data = self
+            .http_client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        for asset in assets {
+            write_atomic(&self.root.join(&asset.path), &data).await?;
+        }
+
+        Ok(AssetManifestEntry {
+            url,
+            sha256: sha256_hex(&data),
+            size: data.len() as u64,
+        })
+    }
+}
+
+fn manifest_key(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+async fn write_atomic(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".part");
+    tokio::fs::write(&partial, data).await?;
+    tokio::fs::rename(&partial, path).await
+}
+
+async fn hash_file(path: &Path) -> std::io::Result<String> {
+    tokio::fs::read(path).await.map(|data| sha256_hex(&data))
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -0,0 +1,104 @@
+//! Collecting and downloading the images referenced by API responses.
+//!
+//! Every response type implements [`ImageAssets`], which lists all image URLs of the
+//! response together with their [`AssetRole`] and a stable relative path such as
+//! `cosmetics/{id}/icon.png`. The [`AssetDownloader`] downloads these assets into a
+//! directory, skipping files that are already present and verified.
+
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+mod collect;
+mod download;
+
+pub use collect::ImageAssets;
+pub use download::{
+    AssetDownloader, AssetError, AssetManifest, AssetManifestEntry, DownloadReport,
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
+pub enum AssetRole {
+    CosmeticSmallIcon,
+    CosmeticIcon,
+    CosmeticFeatured,
+    CosmeticLegoSmall,
+    CosmeticLegoLarge,
+    CosmeticLegoWide,
+    CosmeticBackground,
+    CosmeticCoverArt,
+    CosmeticSeries,
+    CosmeticVariant,
+    BannerSmallIcon,
+    BannerIcon,
+    MapBlank,
+    MapPois,
+    NewsImage,
+    NewsMotdImage,
+    NewsMotdTileImage,
+    NewsMessageImage,
+    PlaylistShowcase,
+    PlaylistMissionIcon,
+    ShopVbuckIcon,
+    ShopBundleImage,
+    ShopOfferImage,
+    ShopOfferBackground,
+    StatsImage,
+}
+
+impl Display for AssetRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A single image referenced by a response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ImageAsset {
+    pub url: String,
+    pub role: AssetRole,
+    /// Path of the image relative to the download directory.
+    pub path: PathBuf,
+}
+
+impl ImageAsset {
+    pub(crate) fn new(url: &str, role: AssetRole, directory: &[&str], name: &str) -> Self {
+        let mut path = PathBuf::new();
+        for component in directory {
+            path.push(sanitize(component));
+        }
+        path.push(format!("{}.{}", sanitize(name), extension(url)));
+
+        ImageAsset {
+            url: url.to_string(),
+            role,
+            path,
+        }
+    }
+}
+
+fn sanitize(component: &str) -> String {
+    let sanitized = component
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+    match sanitized.trim_matches('.') {
+        "" => "_".to_string(),
+        _ => sanitized,
+    }
+}
+
+fn extension(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|url| {
+            Path::new(url.path())
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .filter(|extension| extension.chars().all(|c| c.is_ascii_alphanumeric()))
+                .map(str::to_ascii_lowercase)
+        })
+        .unwrap_or_else(|| "png".to_string())
+}
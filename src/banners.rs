@@ -0,0 +1,96 @@
+//! Lookups over banner colors.
+
+use std::collections::HashMap;
+
+use crate::response_types::banners::{BannersColorV1, BannersColorsV1};
+
+/// Banner colors indexed by ID and category.
+///
+/// ## Example
+///
+/// ```rust
+/// use fortnite_api::banners::BannerColorPalette;
+/// use fortnite_api::response_types::banners::BannersColorV1;
+///
+/// let palette = BannerColorPalette::new(vec![
+///     BannersColorV1 {
+///         id: "DefaultColor1".to_string(),
+///         color: "ff3a3a".to_string(),
+///         category: "Default".to_string(),
+///         sub_category_group: 1,
+///     },
+///     BannersColorV1 {
+///         id: "DefaultColor2".to_string(),
+///         color: "3a68ff".to_string(),
+///         category: "Default".to_string(),
+///         sub_category_group: 1,
+///     },
+/// ]);
+///
+/// assert_eq!(palette.get("DefaultColor2").unwrap().color, "3a68ff");
+/// assert_eq!(palette.by_category("Default").count(), 2);
+/// assert_eq!(palette.categories(), vec!["Default"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BannerColorPalette {
+    colors: BannersColorsV1,
+    by_id: HashMap<String, usize>,
+}
+
+impl BannerColorPalette {
+    pub fn new(colors: BannersColorsV1) -> Self {
+        let by_id = colors
+            .iter()
+            .enumerate()
+            .map(|(index, color)| (color.id.to_lowercase(), index))
+            .collect();
+        BannerColorPalette { colors, by_id }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&BannersColorV1> {
+        //! Get a color by its ID, ignoring case.
+        self.by_id
+            .get(&id.to_lowercase())
+            .and_then(|index| self.colors.get(*index))
+    }
+
+    pub fn by_category<'a>(
+        &'a self,
+        category: &'a str,
+    ) -> impl Iterator<Item = &'a BannersColorV1> + 'a {
+        //! All colors of a category, ignoring case, in API order.
+        self.colors
+            .iter()
+            .filter(move |color| color.category.eq_ignore_ascii_case(category))
+    }
+
+    pub fn by_sub_category_group(
+        &self,
+        sub_category_group: u8,
+    ) -> impl Iterator<Item = &BannersColorV1> {
+        self.colors
+            .iter()
+            .filter(move |color| color.sub_category_group == sub_category_group)
+    }
+
+    pub fn categories(&self) -> Vec<&str> {
+        //! All categories in order of their first appearance.
+        let mut categories = Vec::new();
+        for color in &self.colors {
+            if !categories.contains(&color.category.as_str()) {
+                categories.push(color.category.as_str());
+            }
+        }
+        categories
+    }
+
+    pub fn colors(&self) -> &BannersColorsV1 {
+        &self.colors
+    }
+}
+
+impl From<BannersColorsV1> for BannerColorPalette {
+    fn from(colors: BannersColorsV1) -> Self {
+        BannerColorPalette::new(colors)
+    }
+}
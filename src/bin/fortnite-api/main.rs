@@ -0,0 +1,633 @@
+//! Command-line interface for the Fortnite API.
+//!
+//! Every subcommand mirrors a function of the library. The API key for `stats` is read from
+//! `--api-key`, the `FORTNITE_API_KEY` environment variable or the `api_key` entry of the
+//! config file (`$XDG_CONFIG_HOME/fortnite-api/config.toml` by default).
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use fortnite_api::languages::Language;
+use fortnite_api::response_types::aes::AesKeyFormat;
+use fortnite_api::response_types::cosmetics::CosmeticV2;
+use fortnite_api::response_types::news::News;
+use fortnite_api::response_types::playlists::PlaylistV1;
+use fortnite_api::response_types::stats::{
+    StatsAccountType, StatsImage, StatsStatsInput, StatsTimeWindow,
+};
+use fortnite_api::stats::StatsQuery;
+
+use crate::config::Config;
+use crate::output::{optional, print, OutputFormat, Section};
+
+mod config;
+mod output;
+
+#[derive(Debug, Parser)]
+#[command(name = "fortnite-api", version, about = "Query the Fortnite API")]
+struct Cli {
+    /// Your Fortnite API key, required for `stats`.
+    #[arg(long, global = true, env = "FORTNITE_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+    /// Language code of the returned texts, e.g. `en` or `de`.
+    #[arg(long, short, global = true)]
+    language: Option<String>,
+    /// Output format.
+    #[arg(long, short, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+    /// Path of the config file.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Current AES keys.
+    Aes {
+        #[arg(long, value_enum)]
+        key_format: Option<KeyFormat>,
+    },
+    /// Banners, or banner colors with `--colors`.
+    Banners {
+        #[arg(long)]
+        colors: bool,
+    },
+    /// Battle royale cosmetics.
+    Cosmetics {
+        #[command(subcommand)]
+        command: CosmeticsCommand,
+    },
+    /// Look up a support-a-creator code.
+    Creatorcode { name: String },
+    /// The map and its points of interest.
+    Map,
+    /// News of all game modes or a single one.
+    News {
+        #[arg(value_enum)]
+        mode: Option<NewsMode>,
+    },
+    /// All playlists, or a single playlist by ID.
+    Playlists { id: Option<String> },
+    /// The item shop.
+    Shop {
+        /// Show the combined shop.
+        #[arg(long)]
+        combined: bool,
+    },
+    /// Player stats by name or account ID.
+    Stats {
+        /// Player name, or account ID with `--account-id`.
+        name: String,
+        #[arg(long)]
+        account_id: bool,
+        /// Only for lookups by name, account IDs are unique across platforms.
+        #[arg(long, value_enum, conflicts_with = "account_id")]
+        account_type: Option<AccountType>,
+        #[arg(long, value_enum)]
+        time_window: Option<TimeWindow>,
+        #[arg(long, value_enum)]
+        image: Option<Image>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum CosmeticsCommand {
+    /// All cosmetics.
+    List,
+    /// Cosmetics added in the latest build.
+    New,
+    /// A single cosmetic by ID.
+    Get { id: String },
+    /// Cosmetics whose name contains the query, ignoring case.
+    Search {
+        query: String,
+        /// Only cosmetics of this type, e.g. `outfit`.
+        #[arg(long = "type")]
+        r#type: Option<String>,
+        /// Only cosmetics of this rarity, e.g. `legendary`.
+        #[arg(long)]
+        rarity: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum KeyFormat {
+    Hex,
+    Base64,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum NewsMode {
+    Br,
+    Stw,
+    Creative,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum AccountType {
+    Epic,
+    Psn,
+    Xbl,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum TimeWindow {
+    Season,
+    Lifetime,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Image {
+    All,
+    KeyboardMouse,
+    Gamepad,
+    Touch,
+    None,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let config = Config::load(cli.config.as_deref())?;
+    let api_key = cli.api_key.or(config.api_key);
+    let language = cli
+        .language
+        .or(config.language)
+        .map(|language| language.parse::<Language>())
+        .transpose()?;
+    let language = language.as_ref().map(Language::code);
+    let format = cli.format;
+    let http_client = reqwest::Client::new();
+
+    match cli.command {
+        Command::Aes { key_format } => {
+            let key_format = key_format.map(|key_format| match key_format {
+                KeyFormat::Hex => AesKeyFormat::Hex,
+                KeyFormat::Base64 => AesKeyFormat::Base64,
+            });
+            let aes = fortnite_api::get_aes_keys_v2(&http_client, key_format).await?;
+            print(format, &aes, |aes| {
+                vec![
+                    Section::key_values(
+                        "AES",
+                        vec![
+                            ("Build", aes.build.clone()),
+                            ("Main Key", aes.main_key.to_string()),
+                            ("Updated", aes.updated.to_rfc3339()),
+                        ],
+                    ),
+                    Section::titled(
+                        "Dynamic Keys",
+                        vec!["Pak", "GUID", "Key"],
+                        aes.dynamic_keys
+                            .iter()
+                            .map(|key| {
+                                vec![
+                                    key.pak_filename.clone(),
+                                    key.pak_guid.to_string(),
+                                    key.key.to_string(),
+                                ]
+                            })
+                            .collect(),
+                    ),
+                ]
+            })
+        }
+        Command::Banners { colors: true } => {
+            let colors = fortnite_api::get_banners_colors_v1(&http_client).await?;
+            print(format, &colors, |colors| {
+                vec![Section::new(
+                    vec!["ID", "Color", "Category", "Group"],
+                    colors
+                        .iter()
+                        .map(|color| {
+                            vec![
+                                color.id.clone(),
+                                color.color.clone(),
+                                color.category.clone(),
+                                color.sub_category_group.to_string(),
+                            ]
+                        })
+                        .collect(),
+                )]
+            })
+        }
+        Command::Banners { colors: false } => {
+            let banners = fortnite_api::get_banners_v1(&http_client, language).await?;
+            print(format, &banners, |banners| {
+                vec![Section::new(
+                    vec!["ID", "Category", "Name", "Description"],
+                    banners
+                        .iter()
+                        .map(|banner| {
+                            vec![
+                                banner.id.clone(),
+                                format!("{:?}", banner.category),
+                                banner.dev_name.clone(),
+                                banner.description.clone(),
+                            ]
+                        })
+                        .collect(),
+                )]
+            })
+        }
+        Command::Cosmetics { command } => cosmetics(&http_client, command, language, format).await,
+        Command::Creatorcode { name } => {
+            let code = fortnite_api::get_creatorcode_v2(&http_client, &name).await?;
+            print(format, &code, |code| {
+                vec![Section::key_values(
+                    "Creator Code",
+                    vec![
+                        ("Code", code.code.clone()),
+                        ("Account", code.account.name.clone()),
+                        ("Account ID", code.account.id.clone()),
+                        ("Status", format!("{:?}", code.status)),
+                        ("Verified", code.verified.to_string()),
+                    ],
+                )]
+            })
+        }
+        Command::Map => {
+            let map = fortnite_api::get_map_v1(&http_client, language).await?;
+            print(format, &map, |map| {
+                vec![
+                    Section::key_values(
+                        "Images",
+                        vec![
+                            ("Blank", map.images.blank.clone()),
+                            ("POIs", map.images.pois.clone()),
+                        ],
+                    ),
+                    Section::titled(
+                        "POIs",
+                        vec!["ID", "Name", "X", "Y", "Z"],
+                        map.pois
+                            .iter()
+                            .map(|poi| {
+                                vec![
+                                    poi.id.clone(),
+                                    optional(&poi.name),
+                                    format!("{:.0}", poi.location.x),
+                                    format!("{:.0}", poi.location.y),
+                                    format!("{:.0}", poi.location.z),
+                                ]
+                            })
+                            .collect(),
+                    ),
+                ]
+            })
+        }
+        Command::News { mode: None } => {
+            let news = fortnite_api::get_news_v2(&http_client, language).await?;
+            print(format, &news, |news| {
+                [
+                    ("BR", &news.br),
+                    ("STW", &news.stw),
+                    ("Creative", &news.creative),
+                ]
+                .into_iter()
+                .filter_map(|(mode, news)| news.as_ref().map(|news| news_section(mode, news)))
+                .collect()
+            })
+        }
+        Command::News { mode: Some(mode) } => {
+            let (title, news) = match mode {
+                NewsMode::Br => (
+                    "BR",
+                    fortnite_api::get_news_br_v2(&http_client, language).await?,
+                ),
+                NewsMode::Stw => (
+                    "STW",
+                    fortnite_api::get_news_stw_v2(&http_client, language).await?,
+                ),
+                NewsMode::Creative => (
+                    "Creative",
+                    fortnite_api::get_news_creative_v2(&http_client, language).await?,
+                ),
+            };
+            print(format, &news, |news| vec![news_section(title, news)])
+        }
+        Command::Playlists { id: Some(id) } => {
+            let playlist = fortnite_api::get_playlist_by_id_v1(&http_client, &id, language).await?;
+            print(format, &playlist, |playlist| {
+                vec![playlists_section(std::slice::from_ref(playlist))]
+            })
+        }
+        Command::Playlists { id: None } => {
+            let playlists = fortnite_api::get_playlists_v1(&http_client, language).await?;
+            print(format, &playlists, |playlists| {
+                vec![playlists_section(playlists)]
+            })
+        }
+        Command::Shop { combined } => {
+            let shop = match combined {
+                true => fortnite_api::get_shop_combined_v2(&http_client, language).await?,
+                false => fortnite_api::get_shop_br_v2(&http_client, language).await?,
+            };
+            print(format, &shop, |shop| {
+                vec![Section::titled(
+                    format!("{} ({})", shop.featured.name, shop.date.format("%Y-%m-%d")),
+                    vec!["Offer", "Name", "Items", "Section", "Regular", "Final"],
+                    shop.featured
+                        .entries
+                        .iter()
+                        .map(|entry| {
+                            let name = match &entry.bundle {
+                                Some(bundle) => bundle.name.clone(),
+                                None => entry.dev_name.clone(),
+                            };
+                            let items = entry
+                                .items
+                                .iter()
+                                .map(|item| item.name.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            vec![
+                                entry.offer_id.clone(),
+                                name,
+                                items,
+                                entry.layout.name.clone(),
+                                entry.regular_price.to_string(),
+                                entry.final_price.to_string(),
+                            ]
+                        })
+                        .collect(),
+                )]
+            })
+        }
+        Command::Stats {
+            name,
+            account_id,
+            account_type,
+            time_window,
+            image,
+        } => {
+            let api_key = api_key.ok_or(
+                "missing API key, pass --api-key, set FORTNITE_API_KEY or add api_key to the config file",
+            )?;
+            let mut query = match account_id {
+                true => StatsQuery::by_account_id(name),
+                false => StatsQuery::by_name(name),
+            };
+            if let Some(account_type) = account_type {
+                query = query.with_account_type(match account_type {
+                    AccountType::Epic => StatsAccountType::Epic,
+                    AccountType::Psn => StatsAccountType::Psn,
+                    AccountType::Xbl => StatsAccountType::Xbl,
+                });
+            }
+            if let Some(time_window) = time_window {
+                query = query.with_time_window(match time_window {
+                    TimeWindow::Season => StatsTimeWindow::Season,
+                    TimeWindow::Lifetime => StatsTimeWindow::Lifetime,
+                });
+            }
+            if let Some(image) = image {
+                query = query.with_image(match image {
+                    Image::All => StatsImage::All,
+                    Image::KeyboardMouse => StatsImage::KeyboardMouse,
+                    Image::Gamepad => StatsImage::Gamepad,
+                    Image::Touch => StatsImage::Touch,
+                    Image::None => StatsImage::None,
+                });
+            }
+            let stats = query.fetch(&http_client, api_key).await?;
+            print(format, &stats, |stats| {
+                let mut sections = vec![Section::key_values(
+                    "Account",
+                    vec![
+                        ("Name", stats.account.name.clone()),
+                        ("ID", stats.account.id.clone()),
+                        ("Battle Pass Level", stats.battle_pass.level.to_string()),
+                        (
+                            "Battle Pass Progress",
+                            format!("{}%", stats.battle_pass.progress),
+                        ),
+                        ("Image", optional(&stats.image)),
+                    ],
+                )];
+                let inputs = [
+                    ("All", &stats.stats.all),
+                    ("Keyboard & Mouse", &stats.stats.keyboard_mouse),
+                    ("Gamepad", &stats.stats.gamepad),
+                    ("Touch", &stats.stats.touch),
+                ];
+                for (title, input) in inputs {
+                    if let Some(input) = input {
+                        sections.push(stats_section(title, input));
+                    }
+                }
+                sections
+            })
+        }
+    }
+}
+
+async fn cosmetics(
+    http_client: &reqwest::Client,
+    command: CosmeticsCommand,
+    language: Option<&str>,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        CosmeticsCommand::List => {
+            let cosmetics = fortnite_api::get_cosmetics_v2(http_client, language).await?;
+            print(format, &cosmetics, |cosmetics| {
+                vec![cosmetics_section(cosmetics.iter())]
+            })
+        }
+        CosmeticsCommand::New => {
+            let cosmetics = fortnite_api::get_cosmetics_new_v2(http_client, language).await?;
+            print(format, &cosmetics, |cosmetics| {
+                vec![
+                    Section::key_values(
+                        "Build",
+                        vec![
+                            ("Build", cosmetics.build.clone()),
+                            ("Previous Build", cosmetics.previous_build.clone()),
+                            ("Last Addition", cosmetics.last_addition.to_rfc3339()),
+                        ],
+                    ),
+                    cosmetics_section(cosmetics.items.iter()),
+                ]
+            })
+        }
+        CosmeticsCommand::Get { id } => {
+            let cosmetic = fortnite_api::get_cosmetic_by_id_v2(http_client, &id, language).await?;
+            print(format, &cosmetic, |cosmetic| {
+                vec![Section::key_values(
+                    "Cosmetic",
+                    vec![
+                        ("ID", cosmetic.id.clone()),
+                        ("Name", cosmetic.name.clone()),
+                        ("Description", cosmetic.description.clone()),
+                        ("Type", cosmetic.r#type.display_value.clone()),
+                        ("Rarity", cosmetic.rarity.display_value.clone()),
+                        (
+                            "Set",
+                            optional(&cosmetic.set.as_ref().and_then(|set| set.value.clone())),
+                        ),
+                        (
+                            "Introduction",
+                            optional(&cosmetic.introduction.as_ref().map(|intro| &intro.text)),
+                        ),
+                        ("Added", cosmetic.added.to_rfc3339()),
+                        (
+                            "Shop Appearances",
+                            optional(&cosmetic.shop_history.as_ref().map(Vec::len)),
+                        ),
+                    ],
+                )]
+            })
+        }
+        CosmeticsCommand::Search {
+            query,
+            r#type,
+            rarity,
+        } => {
+            let cosmetics = fortnite_api::get_cosmetics_v2(http_client, language).await?;
+            let query = query.to_lowercase();
+            let matches = |value: &fortnite_api::response_types::cosmetics::CosmeticValue,
+                           filter: &Option<String>| {
+                filter.as_ref().is_none_or(|filter| {
+                    value.value.eq_ignore_ascii_case(filter)
+                        || value.display_value.eq_ignore_ascii_case(filter)
+                })
+            };
+            let found = cosmetics
+                .into_iter()
+                .filter(|cosmetic| cosmetic.name.to_lowercase().contains(&query))
+                .filter(|cosmetic| matches(&cosmetic.r#type, &r#type))
+                .filter(|cosmetic| matches(&cosmetic.rarity, &rarity))
+                .collect::<Vec<_>>();
+            print(format, &found, |found| {
+                vec![cosmetics_section(found.iter())]
+            })
+        }
+    }
+}
+
+fn cosmetics_section<'a>(cosmetics: impl Iterator<Item = &'a CosmeticV2>) -> Section {
+    Section::new(
+        vec!["ID", "Name", "Type", "Rarity", "Introduced", "Added"],
+        cosmetics
+            .map(|cosmetic| {
+                vec![
+                    cosmetic.id.clone(),
+                    cosmetic.name.clone(),
+                    cosmetic.r#type.display_value.clone(),
+                    cosmetic.rarity.display_value.clone(),
+                    optional(
+                        &cosmetic
+                            .introduction
+                            .as_ref()
+                            .map(|intro| format!("C{}S{}", intro.chapter, intro.season)),
+                    ),
+                    cosmetic.added.format("%Y-%m-%d").to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+fn news_section(title: &str, news: &News) -> Section {
+    let rows = match news {
+        News::NewsContent(content) => content
+            .motds
+            .iter()
+            .flatten()
+            .map(|motd| vec![motd.title.clone(), motd.body.clone()])
+            .chain(
+                content
+                    .messages
+                    .iter()
+                    .flatten()
+                    .map(|message| vec![message.title.clone(), message.body.clone()]),
+            )
+            .collect(),
+        News::NoNews => vec![],
+    };
+    Section::titled(title, vec!["Title", "Body"], rows)
+}
+
+fn playlists_section(playlists: &[PlaylistV1]) -> Section {
+    Section::new(
+        vec![
+            "ID",
+            "Name",
+            "Sub Name",
+            "Players",
+            "Team Size",
+            "LTM",
+            "Added",
+        ],
+        playlists
+            .iter()
+            .map(|playlist| {
+                vec![
+                    playlist.id.clone(),
+                    optional(&playlist.name),
+                    optional(&playlist.sub_name),
+                    playlist.max_players.to_string(),
+                    playlist.max_team_size.to_string(),
+                    playlist.is_limited_time_mode.to_string(),
+                    playlist.added.format("%Y-%m-%d").to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+fn stats_section(title: &str, input: &StatsStatsInput) -> Section {
+    let overall = &input.overall;
+    let mut rows = vec![vec![
+        "Overall".to_string(),
+        overall.matches.to_string(),
+        overall.wins.to_string(),
+        format!("{:.2}%", overall.win_rate),
+        overall.kills.to_string(),
+        format!("{:.2}", overall.kd),
+        overall.minutes_played.to_string(),
+    ]];
+    let modes = [
+        ("Solo", &input.solo),
+        ("Duo", &input.duo),
+        ("Trio", &input.trio),
+        ("Squad", &input.squad),
+        ("LTM", &input.ltm),
+    ];
+    for (name, mode) in modes {
+        if let Some(mode) = mode {
+            rows.push(vec![
+                name.to_string(),
+                mode.matches.to_string(),
+                mode.wins.to_string(),
+                format!("{:.2}%", mode.win_rate),
+                mode.kills.to_string(),
+                format!("{:.2}", mode.kd),
+                mode.minutes_played.to_string(),
+            ]);
+        }
+    }
+    Section::titled(
+        title,
+        vec![
+            "Mode", "Matches", "Wins", "Win Rate", "Kills", "K/D", "Minutes",
+        ],
+        rows,
+    )
+}
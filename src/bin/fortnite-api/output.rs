@@ -0,0 +1,86 @@
+use std::error::Error;
+
+use clap::ValueEnum;
+use comfy_table::{presets::UTF8_FULL_CONDENSED, ContentArrangement, Table};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+}
+
+/// A titled table printed in table output mode.
+pub struct Section {
+    pub title: Option<String>,
+    pub headers: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Section {
+    pub fn new(headers: Vec<&'static str>, rows: Vec<Vec<String>>) -> Self {
+        Section {
+            title: None,
+            headers,
+            rows,
+        }
+    }
+
+    pub fn titled(
+        title: impl Into<String>,
+        headers: Vec<&'static str>,
+        rows: Vec<Vec<String>>,
+    ) -> Self {
+        Section {
+            title: Some(title.into()),
+            headers,
+            rows,
+        }
+    }
+
+    pub fn key_values(title: impl Into<String>, rows: Vec<(&'static str, String)>) -> Self {
+        Section::titled(
+            title,
+            vec!["Field", "Value"],
+            rows.into_iter()
+                .map(|(key, value)| vec![key.to_string(), value])
+                .collect(),
+        )
+    }
+}
+
+pub fn print<T: Serialize>(
+    format: OutputFormat,
+    value: &T,
+    sections: impl FnOnce(&T) -> Vec<Section>,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+        OutputFormat::Yaml => print!("{}", serde_yaml::to_string(value)?),
+        OutputFormat::Table => {
+            for (index, section) in sections(value).into_iter().enumerate() {
+                if index > 0 {
+                    println!();
+                }
+                if let Some(title) = &section.title {
+                    println!("{title}");
+                }
+                let mut table = Table::new();
+                table
+                    .load_preset(UTF8_FULL_CONDENSED)
+                    .set_content_arrangement(ContentArrangement::Dynamic)
+                    .set_header(&section.headers);
+                for row in section.rows {
+                    table.add_row(row);
+                }
+                println!("{table}");
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
@@ -0,0 +1,165 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::response_types::cosmetics::CosmeticV2;
+
+/// Analytics over the shop appearances of a cosmetic.
+///
+/// Appearances on the same day are counted once.
+///
+/// ## Example
+///
+/// ```rust
+/// use chrono::{TimeDelta, TimeZone, Utc};
+/// use fortnite_api::cosmetics::ShopHistoryStats;
+///
+/// let history = [
+///     Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap(),
+///     Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap(),
+/// ];
+/// let stats = ShopHistoryStats::new(&history).unwrap();
+///
+/// assert_eq!(stats.appearances, 3);
+/// assert_eq!(stats.average_gap, Some(TimeDelta::days(20)));
+/// assert_eq!(stats.longest_absence, Some(TimeDelta::days(30)));
+///
+/// let now = Utc.with_ymd_and_hms(2024, 2, 20, 0, 0, 0).unwrap();
+/// assert_eq!(stats.days_since_last_seen(now), 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShopHistoryStats {
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub appearances: usize,
+    /// The average time between two appearances, if the item appeared more than once.
+    pub average_gap: Option<TimeDelta>,
+    /// The longest time between two appearances, if the item appeared more than once.
+    pub longest_absence: Option<TimeDelta>,
+}
+
+impl ShopHistoryStats {
+    pub fn new(history: &[DateTime<Utc>]) -> Option<Self> {
+        //! Compute the stats of a shop history. Returns `None` for an empty history.
+        let mut dates = history.to_vec();
+        dates.sort_unstable();
+        dates.dedup_by_key(|date| date.date_naive());
+
+        let first_seen = *dates.first()?;
+        let last_seen = *dates.last()?;
+        let gaps = dates
+            .windows(2)
+            .filter_map(|pair| Some(*pair.get(1)? - *pair.first()?))
+            .collect::<Vec<_>>();
+        let average_gap = i32::try_from(gaps.len())
+            .ok()
+            .filter(|count| *count > 0)
+            .map(|count| (last_seen - first_seen) / count);
+        Some(ShopHistoryStats {
+            first_seen,
+            last_seen,
+            appearances: dates.len(),
+            average_gap,
+            longest_absence: gaps.into_iter().max(),
+        })
+    }
+
+    pub fn from_cosmetic(cosmetic: &CosmeticV2) -> Option<Self> {
+        //! Compute the stats of a cosmetic's `shop_history`. Returns `None` if it never
+        //! appeared in the shop.
+        Self::new(cosmetic.shop_history.as_deref()?)
+    }
+
+    pub fn time_since_last_seen(&self, now: DateTime<Utc>) -> TimeDelta {
+        now - self.last_seen
+    }
+
+    pub fn days_since_last_seen(&self, now: DateTime<Utc>) -> i64 {
+        self.time_since_last_seen(now).num_days()
+    }
+
+    pub fn expected_return(&self) -> Option<DateTime<Utc>> {
+        //! When the item would return if it kept its average cadence.
+        self.average_gap.map(|gap| self.last_seen + gap)
+    }
+
+    pub fn return_score(&self, now: DateTime<Utc>) -> f64 {
+        //! How likely the item is to return soon, between 0 and 1.
+        //!
+        //! The score grows as the current absence approaches the average gap, peaks when the
+        //! item is due and falls off again once it is long overdue, as such items have often
+        //! been vaulted. It is scaled down for items with few appearances.
+        let Some(gap) = self.average_gap.filter(|gap| *gap > TimeDelta::zero()) else {
+            return 0.0;
+        };
+        let absence = self.time_since_last_seen(now).num_seconds().max(0) as f64;
+        let ratio = absence / gap.num_seconds() as f64;
+        let cadence = if ratio <= 1.0 {
+            ratio
+        } else {
+            1.0 / (1.0 + (ratio - 1.0).powi(2))
+        };
+        let confidence = 1.0 - 1.0 / self.appearances as f64;
+        cadence * confidence
+    }
+}
+
+/// A cosmetic ranked by [`likely_to_return`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnCandidate<'a> {
+    pub cosmetic: &'a CosmeticV2,
+    pub stats: ShopHistoryStats,
+    pub score: f64,
+}
+
+pub fn shop_history_stats<'a>(
+    cosmetics: impl IntoIterator<Item = &'a CosmeticV2>,
+) -> Vec<(&'a CosmeticV2, ShopHistoryStats)> {
+    //! Compute the shop history stats of every cosmetic that appeared in the shop.
+    cosmetics
+        .into_iter()
+        .filter_map(|cosmetic| Some((cosmetic, ShopHistoryStats::from_cosmetic(cosmetic)?)))
+        .collect()
+}
+
+pub fn likely_to_return<'a>(
+    cosmetics: impl IntoIterator<Item = &'a CosmeticV2>,
+    now: DateTime<Utc>,
+) -> Vec<ReturnCandidate<'a>> {
+    //! Rank cosmetics by how likely they are to return to the shop soon, based on
+    //! [`ShopHistoryStats::return_score`].
+    //!
+    //! ## Parameters
+    //!
+    //! - `cosmetics`: The cosmetics to rank.
+    //! - `now`: The time to rank at.
+    //!
+    //! ## Returns
+    //!
+    //! The cosmetics with a non-zero score, most likely first.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! # #[tokio::main]
+    //! # async fn main() {
+    //! let http_client = reqwest::Client::new();
+    //! let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await.unwrap();
+    //!
+    //! let candidates = fortnite_api::cosmetics::likely_to_return(&cosmetics, chrono::Utc::now());
+    //! for candidate in candidates.iter().take(10) {
+    //!     println!("{} ({:.2})", candidate.cosmetic.name, candidate.score);
+    //! }
+    //! # }
+    //! ```
+    let mut candidates: Vec<ReturnCandidate> = shop_history_stats(cosmetics)
+        .into_iter()
+        .map(|(cosmetic, stats)| ReturnCandidate {
+            cosmetic,
+            stats,
+            score: stats.return_score(now),
+        })
+        .filter(|candidate| candidate.score > 0.0)
+        .collect();
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates
+}
@@ -0,0 +1,302 @@
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, Utc};
+
+use crate::cosmetics::search::SearchText;
+use crate::response_types::cosmetics::{CosmeticV2, CosmeticsV2};
+
+/// A query over a [`CosmeticIndex`].
+///
+/// Text values are compared case-insensitively against the value, the display value and the
+/// backend value, so `Type("backpack".into())`, `Type("Back Bling".into())` and
+/// `Type("AthenaBackpack".into())` are equivalent. Date ranges include `from` and exclude `to`; a missing bound is open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CosmeticFilter {
+    Type(String),
+    Rarity(String),
+    Series(String),
+    Set(String),
+    Introduced {
+        chapter: String,
+        season: Option<String>,
+    },
+    GameplayTag(String),
+    MetaTag(String),
+    AddedBetween {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+    HasVariants,
+    HasBuiltInEmotes,
+    /// Cosmetics that appeared in the shop at least once within the window.
+    InShopBetween {
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    },
+    All(Vec<CosmeticFilter>),
+    Any(Vec<CosmeticFilter>),
+    Not(Box<CosmeticFilter>),
+}
+
+impl CosmeticFilter {
+    pub fn and(self, other: CosmeticFilter) -> Self {
+        //! Combine two filters so that both have to match.
+        match self {
+            CosmeticFilter::All(mut filters) => {
+                filters.push(other);
+                CosmeticFilter::All(filters)
+            }
+            filter => CosmeticFilter::All(vec![filter, other]),
+        }
+    }
+
+    pub fn or(self, other: CosmeticFilter) -> Self {
+        //! Combine two filters so that either has to match.
+        match self {
+            CosmeticFilter::Any(mut filters) => {
+                filters.push(other);
+                CosmeticFilter::Any(filters)
+            }
+            filter => CosmeticFilter::Any(vec![filter, other]),
+        }
+    }
+}
+
+impl std::ops::Not for CosmeticFilter {
+    type Output = CosmeticFilter;
+
+    fn not(self) -> Self::Output {
+        CosmeticFilter::Not(Box::new(self))
+    }
+}
+
+/// Cosmetics indexed by their attributes for repeated offline queries.
+///
+/// ## Example
+///
+/// ```rust no_run
+/// use fortnite_api::cosmetics::{CosmeticFilter, CosmeticIndex};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let http_client = reqwest::Client::new();
+/// let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await.unwrap();
+/// let index = CosmeticIndex::new(cosmetics);
+///
+/// let rarity = CosmeticFilter::Rarity("legendary".into())
+///     .or(CosmeticFilter::Series("Marvel Series".into()));
+/// let filter = CosmeticFilter::Type("outfit".into())
+///     .and(rarity)
+///     .and(!CosmeticFilter::HasVariants);
+/// for cosmetic in index.query(&filter) {
+///     println!("{}", cosmetic.name);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CosmeticIndex {
+    pub(super) cosmetics: CosmeticsV2,
+    pub(super) by_id: HashMap<String, usize>,
+    by_type: HashMap<String, BTreeSet<usize>>,
+    by_rarity: HashMap<String, BTreeSet<usize>>,
+    by_series: HashMap<String, BTreeSet<usize>>,
+    by_set: HashMap<String, BTreeSet<usize>>,
+    by_chapter: HashMap<String, BTreeSet<usize>>,
+    by_season: HashMap<(String, String), BTreeSet<usize>>,
+    by_gameplay_tag: HashMap<String, BTreeSet<usize>>,
+    by_meta_tag: HashMap<String, BTreeSet<usize>>,
+    by_added: Vec<(DateTime<Utc>, usize)>,
+    shop_appearances: Vec<(DateTime<Utc>, usize)>,
+    with_variants: BTreeSet<usize>,
+    with_built_in_emotes: BTreeSet<usize>,
+    pub(super) search_texts: Vec<SearchText>,
+}
+
+impl CosmeticIndex {
+    pub fn new(cosmetics: CosmeticsV2) -> Self {
+        let mut index = CosmeticIndex::default();
+        for (position, cosmetic) in cosmetics.iter().enumerate() {
+            index.by_id.insert(cosmetic.id.to_lowercase(), position);
+            index
+                .search_texts
+                .extend(SearchText::collect(cosmetic, position, None));
+            for value in [
+                &cosmetic.r#type.value,
+                &cosmetic.r#type.display_value,
+                &cosmetic.r#type.backend_value,
+            ] {
+                insert(&mut index.by_type, value, position);
+            }
+            for value in [
+                &cosmetic.rarity.value,
+                &cosmetic.rarity.display_value,
+                &cosmetic.rarity.backend_value,
+            ] {
+                insert(&mut index.by_rarity, value, position);
+            }
+            if let Some(series) = &cosmetic.series {
+                insert(&mut index.by_series, &series.value, position);
+                insert(&mut index.by_series, &series.backend_value, position);
+            }
+            if let Some(set) = &cosmetic.set {
+                if let Some(value) = &set.value {
+                    insert(&mut index.by_set, value, position);
+                }
+                insert(&mut index.by_set, &set.backend_value, position);
+            }
+            if let Some(introduction) = &cosmetic.introduction {
+                insert(&mut index.by_chapter, &introduction.chapter, position);
+                index
+                    .by_season
+                    .entry((
+                        introduction.chapter.to_lowercase(),
+                        introduction.season.to_lowercase(),
+                    ))
+                    .or_default()
+                    .insert(position);
+            }
+            for tag in cosmetic.gameplay_tags.iter().flatten() {
+                insert(&mut index.by_gameplay_tag, tag, position);
+            }
+            for tag in cosmetic.meta_tags.iter().flatten() {
+                insert(&mut index.by_meta_tag, tag, position);
+            }
+            index.by_added.push((cosmetic.added, position));
+            for date in cosmetic.shop_history.iter().flatten() {
+                index.shop_appearances.push((*date, position));
+            }
+            if cosmetic.variants.as_ref().is_some_and(|v| !v.is_empty()) {
+                index.with_variants.insert(position);
+            }
+            if cosmetic
+                .built_in_emote_ids
+                .as_ref()
+                .is_some_and(|ids| !ids.is_empty())
+            {
+                index.with_built_in_emotes.insert(position);
+            }
+        }
+        index.by_added.sort_unstable();
+        index.shop_appearances.sort_unstable();
+        index.cosmetics = cosmetics;
+        index
+    }
+
+    pub fn cosmetics(&self) -> &CosmeticsV2 {
+        &self.cosmetics
+    }
+
+    pub fn len(&self) -> usize {
+        self.cosmetics.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cosmetics.is_empty()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&CosmeticV2> {
+        //! Get a cosmetic by its ID, ignoring case.
+        self.by_id
+            .get(&id.to_lowercase())
+            .and_then(|position| self.cosmetics.get(*position))
+    }
+
+    pub fn query(&self, filter: &CosmeticFilter) -> Vec<&CosmeticV2> {
+        //! Get all cosmetics matching `filter`, in their original order.
+        self.evaluate(filter)
+            .into_iter()
+            .filter_map(|position| self.cosmetics.get(position))
+            .collect()
+    }
+
+    pub fn count(&self, filter: &CosmeticFilter) -> usize {
+        //! Count the cosmetics matching `filter`.
+        self.evaluate(filter).len()
+    }
+
+    pub(super) fn evaluate(&self, filter: &CosmeticFilter) -> BTreeSet<usize> {
+        match filter {
+            CosmeticFilter::Type(value) => lookup(&self.by_type, value),
+            CosmeticFilter::Rarity(value) => lookup(&self.by_rarity, value),
+            CosmeticFilter::Series(value) => lookup(&self.by_series, value),
+            CosmeticFilter::Set(value) => lookup(&self.by_set, value),
+            CosmeticFilter::Introduced {
+                chapter,
+                season: None,
+            } => lookup(&self.by_chapter, chapter),
+            CosmeticFilter::Introduced {
+                chapter,
+                season: Some(season),
+            } => self
+                .by_season
+                .get(&(chapter.to_lowercase(), season.to_lowercase()))
+                .cloned()
+                .unwrap_or_default(),
+            CosmeticFilter::GameplayTag(tag) => lookup(&self.by_gameplay_tag, tag),
+            CosmeticFilter::MetaTag(tag) => lookup(&self.by_meta_tag, tag),
+            CosmeticFilter::AddedBetween { from, to } => range(&self.by_added, *from, *to),
+            CosmeticFilter::HasVariants => self.with_variants.clone(),
+            CosmeticFilter::HasBuiltInEmotes => self.with_built_in_emotes.clone(),
+            CosmeticFilter::InShopBetween { from, to } => range(&self.shop_appearances, *from, *to),
+            CosmeticFilter::All(filters) => {
+                let mut filters = filters.iter();
+                let Some(first) = filters.next() else {
+                    return (0..self.cosmetics.len()).collect();
+                };
+                let mut positions = self.evaluate(first);
+                for filter in filters {
+                    if positions.is_empty() {
+                        break;
+                    }
+                    let other = self.evaluate(filter);
+                    positions.retain(|position| other.contains(position));
+                }
+                positions
+            }
+            CosmeticFilter::Any(filters) => filters
+                .iter()
+                .flat_map(|filter| self.evaluate(filter))
+                .collect(),
+            CosmeticFilter::Not(filter) => {
+                let excluded = self.evaluate(filter);
+                (0..self.cosmetics.len())
+                    .filter(|position| !excluded.contains(position))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl From<CosmeticsV2> for CosmeticIndex {
+    fn from(cosmetics: CosmeticsV2) -> Self {
+        CosmeticIndex::new(cosmetics)
+    }
+}
+
+fn insert(index: &mut HashMap<String, BTreeSet<usize>>, key: &str, position: usize) {
+    index
+        .entry(key.to_lowercase())
+        .or_default()
+        .insert(position);
+}
+
+fn lookup(index: &HashMap<String, BTreeSet<usize>>, key: &str) -> BTreeSet<usize> {
+    index.get(&key.to_lowercase()).cloned().unwrap_or_default()
+}
+
+fn range(
+    sorted: &[(DateTime<Utc>, usize)],
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> BTreeSet<usize> {
+    let start = from.map_or(0, |from| sorted.partition_point(|(date, _)| *date < from));
+    let end = to.map_or(sorted.len(), |to| {
+        sorted.partition_point(|(date, _)| *date < to)
+    });
+    sorted
+        .get(start..end.max(start))
+        .unwrap_or_default()
+        .iter()
+        .map(|(_, position)| *position)
+        .collect()
+}
@@ -0,0 +1,9 @@
+//! Offline lookups, fuzzy search and shop history analytics over cosmetics.
+
+mod history;
+mod index;
+mod search;
+
+pub use history::{likely_to_return, shop_history_stats, ReturnCandidate, ShopHistoryStats};
+pub use index::{CosmeticFilter, CosmeticIndex};
+pub use search::{SearchField, SearchMatch, SearchOptions};
@@ -0,0 +1,320 @@
+use std::cmp::Ordering;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::cosmetics::{CosmeticFilter, CosmeticIndex};
+use crate::response_types::cosmetics::{CosmeticV2, CosmeticsV2};
+
+/// The text of a cosmetic a search matched against.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SearchField {
+    Name,
+    Description,
+    SetText,
+}
+
+impl SearchField {
+    fn weight(self) -> f64 {
+        match self {
+            SearchField::Name => 1.0,
+            SearchField::SetText => 0.85,
+            SearchField::Description => 0.7,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchOptions {
+    /// Matches scoring below this are dropped. Scores range from 0 to 1.
+    pub min_score: f64,
+    /// The maximum number of matches to return.
+    pub limit: Option<usize>,
+    /// The fields to search in.
+    pub fields: Vec<SearchField>,
+    /// Only search cosmetics matching this filter.
+    pub filter: Option<CosmeticFilter>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            min_score: 0.5,
+            limit: Some(10),
+            fields: vec![
+                SearchField::Name,
+                SearchField::SetText,
+                SearchField::Description,
+            ],
+            filter: None,
+        }
+    }
+}
+
+/// A ranked search result, carrying the best scoring text of the cosmetic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch<'a> {
+    pub cosmetic: &'a CosmeticV2,
+    pub score: f64,
+    pub field: SearchField,
+    /// The language added via [`CosmeticIndex::add_language`] the text is in, or `None` for
+    /// the language of the indexed cosmetics.
+    pub language: Option<&'a str>,
+    pub text: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SearchText {
+    position: usize,
+    language: Option<String>,
+    field: SearchField,
+    original: String,
+    normalized: String,
+    compact: String,
+    tokens: Vec<Vec<char>>,
+}
+
+impl SearchText {
+    pub(crate) fn collect(
+        cosmetic: &CosmeticV2,
+        position: usize,
+        language: Option<&str>,
+    ) -> Vec<SearchText> {
+        let set_text = cosmetic.set.as_ref().and_then(|set| set.text.as_deref());
+        [
+            (SearchField::Name, Some(cosmetic.name.as_str())),
+            (
+                SearchField::Description,
+                Some(cosmetic.description.as_str()),
+            ),
+            (SearchField::SetText, set_text),
+        ]
+        .into_iter()
+        .filter_map(|(field, text)| {
+            let text = text?;
+            let normalized = normalize(text);
+            if normalized.is_empty() {
+                return None;
+            }
+            Some(SearchText {
+                position,
+                language: language.map(str::to_string),
+                field,
+                original: text.to_string(),
+                compact: normalized.replace(' ', ""),
+                tokens: normalized
+                    .split(' ')
+                    .map(|token| token.chars().collect())
+                    .collect(),
+                normalized,
+            })
+        })
+        .collect()
+    }
+}
+
+impl CosmeticIndex {
+    pub fn add_language(&mut self, language: &str, cosmetics: &CosmeticsV2) {
+        //! Make the names and descriptions of `cosmetics` in another language searchable.
+        //! Cosmetics are matched to the index by ID; unknown IDs are ignored.
+        //!
+        //! ## Parameters
+        //!
+        //! - `language`: The language of `cosmetics`, as passed to the endpoint.
+        //! - `cosmetics`: The cosmetics fetched in that language.
+        for cosmetic in cosmetics {
+            if let Some(position) = self.by_id.get(&cosmetic.id.to_lowercase()) {
+                let texts = SearchText::collect(cosmetic, *position, Some(language));
+                self.search_texts.extend(texts);
+            }
+        }
+    }
+
+    pub fn search(&self, query: &str, options: &SearchOptions) -> Vec<SearchMatch<'_>> {
+        //! Search cosmetics by name, tolerating typos, partial words and missing accents.
+        //!
+        //! ## Parameters
+        //!
+        //! - `query`: The text to search for.
+        //! - `options`: Which fields to search and how many matches to return.
+        //!
+        //! ## Returns
+        //!
+        //! The matching cosmetics, best match first.
+        //!
+        //! ## Example
+        //!
+        //! ```rust no_run
+        //! use fortnite_api::cosmetics::{CosmeticIndex, SearchOptions};
+        //!
+        //! # #[tokio::main]
+        //! # async fn main() {
+        //! let http_client = reqwest::Client::new();
+        //! let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await.unwrap();
+        //! let german = fortnite_api::get_cosmetics_v2(&http_client, Some("de")).await.unwrap();
+        //!
+        //! let mut index = CosmeticIndex::new(cosmetics);
+        //! index.add_language("de", &german);
+        //!
+        //! for found in index.search("renegde raidr", &SearchOptions::default()) {
+        //!     println!("{} ({:.2})", found.cosmetic.name, found.score);
+        //! }
+        //! # }
+        //! ```
+        let query = Query::new(query);
+        if query.normalized.is_empty() {
+            return vec![];
+        }
+        let allowed = options.filter.as_ref().map(|filter| self.evaluate(filter));
+
+        let mut best: Vec<Option<(f64, &SearchText)>> = vec![None; self.cosmetics.len()];
+        for text in &self.search_texts {
+            if !options.fields.contains(&text.field)
+                || allowed
+                    .as_ref()
+                    .is_some_and(|allowed| !allowed.contains(&text.position))
+            {
+                continue;
+            }
+            let score = query.score(text) * text.field.weight();
+            if score < options.min_score {
+                continue;
+            }
+            if let Some(slot) = best.get_mut(text.position) {
+                if slot.is_none_or(|(best_score, _)| score > best_score) {
+                    *slot = Some((score, text));
+                }
+            }
+        }
+
+        let mut matches: Vec<SearchMatch> = best
+            .into_iter()
+            .flatten()
+            .filter_map(|(score, text)| {
+                Some(SearchMatch {
+                    cosmetic: self.cosmetics.get(text.position)?,
+                    score,
+                    field: text.field,
+                    language: text.language.as_deref(),
+                    text: &text.original,
+                })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+        if let Some(limit) = options.limit {
+            matches.truncate(limit);
+        }
+        matches
+    }
+}
+
+struct Query {
+    normalized: String,
+    compact: String,
+    tokens: Vec<Vec<char>>,
+}
+
+impl Query {
+    fn new(query: &str) -> Self {
+        let normalized = normalize(query);
+        Query {
+            compact: normalized.replace(' ', ""),
+            tokens: normalized
+                .split(' ')
+                .filter(|token| !token.is_empty())
+                .map(|token| token.chars().collect())
+                .collect(),
+            normalized,
+        }
+    }
+
+    fn score(&self, text: &SearchText) -> f64 {
+        if text.normalized == self.normalized {
+            return 1.0;
+        }
+        if text.compact == self.compact {
+            return 0.95;
+        }
+        if text.normalized.starts_with(&self.normalized) {
+            return 0.9;
+        }
+        if text
+            .normalized
+            .split(' ')
+            .any(|word| word.starts_with(&self.normalized))
+        {
+            return 0.85;
+        }
+        if text.normalized.contains(&self.normalized) || text.compact.contains(&self.compact) {
+            return 0.75;
+        }
+        let similarity = self
+            .tokens
+            .iter()
+            .map(|query_token| {
+                text.tokens
+                    .iter()
+                    .map(|token| token_similarity(query_token, token))
+                    .fold(0.0, f64::max)
+            })
+            .sum::<f64>()
+            / self.tokens.len().max(1) as f64;
+        similarity * 0.75
+    }
+}
+
+/// Lowercase, strip diacritics and collapse everything that isn't a letter or digit into
+/// single spaces.
+fn normalize(text: &str) -> String {
+    let folded: String = text
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Similarity of two words between 0 and 1, also comparing `query` against the start of
+/// `token` so that misspelled prefixes still match.
+fn token_similarity(query: &[char], token: &[char]) -> f64 {
+    let full = similarity(query, token);
+    let prefix = token
+        .get(..query.len())
+        .filter(|_| token.len() > query.len())
+        .map_or(0.0, |prefix| similarity(query, prefix) - 0.05);
+    full.max(prefix)
+}
+
+fn similarity(a: &[char], b: &[char]) -> f64 {
+    let length = a.len().max(b.len());
+    if length == 0 {
+        return 0.0;
+    }
+    1.0 - edit_distance(a, b) as f64 / length as f64
+}
+
+/// Optimal string alignment distance, counting adjacent transpositions as a single edit.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, char_a) in a.iter().enumerate() {
+        let mut current = Vec::with_capacity(b.len() + 1);
+        current.push(i + 1);
+        for (j, char_b) in b.iter().enumerate() {
+            let substitution =
+                previous.get(j).copied().unwrap_or_default() + usize::from(char_a != char_b);
+            let insertion = current.get(j).copied().unwrap_or_default() + 1;
+            let deletion = previous.get(j + 1).copied().unwrap_or_default() + 1;
+            let mut cost = substitution.min(insertion).min(deletion);
+            let transposed =
+                i > 0 && j > 0 && a.get(i - 1) == Some(char_b) && b.get(j - 1) == Some(char_a);
+            if transposed {
+                cost = cost.min(before.get(j - 1).copied().unwrap_or_default() + 1);
+            }
+            current.push(cost);
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous.last().copied().unwrap_or_default()
+}
@@ -0,0 +1,259 @@
+//! Batch checks and change tracking of support-a-creator codes.
+
+use std::collections::BTreeMap;
+
+use futures_util::{stream, StreamExt};
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::response_types::creatorcode::{CreatorCodeAccount, CreatorCodeStatus, CreatorCodeV2};
+use crate::utils::fetch::fetch_endpoint_checked;
+
+/// The result of looking up a single creator code.
+#[derive(Debug)]
+pub enum CreatorCodeLookup {
+    Found(CreatorCodeV2),
+    NotFound,
+    /// The lookup failed, e.g. because of a network error. Says nothing about the code.
+    Failed(reqwest::Error),
+}
+
+impl CreatorCodeLookup {
+    pub fn creator_code(&self) -> Option<&CreatorCodeV2> {
+        match self {
+            CreatorCodeLookup::Found(creator_code) => Some(creator_code),
+            _ => None,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.creator_code()
+            .is_some_and(|creator_code| creator_code.status == CreatorCodeStatus::Active)
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.creator_code()
+            .is_some_and(|creator_code| creator_code.verified)
+    }
+
+    pub fn account(&self) -> Option<&CreatorCodeAccount> {
+        //! The account owning the code.
+        self.creator_code()
+            .map(|creator_code| &creator_code.account)
+    }
+}
+
+pub async fn check_creator_code(http_client: &reqwest::Client, code: &str) -> CreatorCodeLookup {
+    //! Look up a creator code, telling unknown codes apart from failed requests.
+    let mut url = Url::parse("https://fortnite-api.com/v2/creatorcode").unwrap();
+    url.query_pairs_mut().append_pair("name", code.trim());
+    match fetch_endpoint_checked(http_client, url, "GET", "", &Default::default()).await {
+        Ok(creator_code) => CreatorCodeLookup::Found(creator_code),
+        Err(error) if error.status() == Some(StatusCode::NOT_FOUND) => CreatorCodeLookup::NotFound,
+        Err(error) => CreatorCodeLookup::Failed(error),
+    }
+}
+
+pub async fn check_creator_codes<I, S>(
+    http_client: &reqwest::Client,
+    codes: I,
+    concurrency: usize,
+) -> Vec<(String, CreatorCodeLookup)>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    //! Look up many creator codes.
+    //!
+    //! ## Parameters
+    //!
+    //! - `http_client`: The reqwest client.
+    //! - `codes`: The creator codes to check.
+    //! - `concurrency`: The maximum number of simultaneous requests.
+    //!
+    //! ## Returns
+    //!
+    //! Each code with its lookup result, in the given order.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!
+    //!     let results =
+    //!         fortnite_api::creator_codes::check_creator_codes(&http_client, ["trymacs", "ninja"], 4)
+    //!             .await;
+    //!     for (code, lookup) in &results {
+    //!         println!("{code}: active {}, verified {}", lookup.is_active(), lookup.is_verified());
+    //!     }
+    //! }
+    //! ```
+    stream::iter(codes.into_iter().map(Into::into))
+        .map(|code: String| async move {
+            let lookup = check_creator_code(http_client, &code).await;
+            (code, lookup)
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// The last known state of a creator code.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase", tag = "state")]
+pub enum CreatorCodeState {
+    Found {
+        status: CreatorCodeStatus,
+        verified: bool,
+        account: CreatorCodeAccount,
+    },
+    NotFound,
+}
+
+impl CreatorCodeState {
+    fn from_lookup(lookup: &CreatorCodeLookup) -> Option<Self> {
+        match lookup {
+            CreatorCodeLookup::Found(creator_code) => Some(CreatorCodeState::Found {
+                status: creator_code.status,
+                verified: creator_code.verified,
+                account: creator_code.account.clone(),
+            }),
+            CreatorCodeLookup::NotFound => Some(CreatorCodeState::NotFound),
+            CreatorCodeLookup::Failed(_) => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum CreatorCodeChangeKind {
+    StatusChanged {
+        from: CreatorCodeStatus,
+        to: CreatorCodeStatus,
+    },
+    AccountChanged {
+        from: CreatorCodeAccount,
+        to: CreatorCodeAccount,
+    },
+    VerifiedChanged {
+        verified: bool,
+    },
+    /// A code that was found before no longer exists.
+    Removed,
+    /// A code that didn't exist before was created.
+    Created,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatorCodeChange {
+    pub code: String,
+    pub change: CreatorCodeChangeKind,
+}
+
+/// Tracks creator codes between checks and reports what changed.
+///
+/// Codes are compared ignoring case. The first check of a code only records its state, and
+/// failed lookups leave the known state untouched. The monitor is serializable so it can be
+/// persisted between runs.
+///
+/// ## Example
+///
+/// ```rust no_run
+/// use fortnite_api::creator_codes::{check_creator_codes, CreatorCodeMonitor};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let http_client = reqwest::Client::new();
+///     let mut monitor = CreatorCodeMonitor::new();
+///
+///     loop {
+///         let results = check_creator_codes(&http_client, ["trymacs", "ninja"], 4).await;
+///         for change in monitor.update(&results) {
+///             println!("{}: {:?}", change.code, change.change);
+///         }
+///         tokio::time::sleep(std::time::Duration::from_secs(3600)).await;
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+#[serde(transparent)]
+pub struct CreatorCodeMonitor {
+    states: BTreeMap<String, CreatorCodeState>,
+}
+
+impl CreatorCodeMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn state(&self, code: &str) -> Option<&CreatorCodeState> {
+        self.states.get(&code.trim().to_lowercase())
+    }
+
+    pub fn update(&mut self, results: &[(String, CreatorCodeLookup)]) -> Vec<CreatorCodeChange> {
+        //! Record the results of a check.
+        //!
+        //! ## Returns
+        //!
+        //! The changes since the previous check, in the order of `results`.
+        let mut changes = Vec::new();
+        for (code, lookup) in results {
+            let Some(state) = CreatorCodeState::from_lookup(lookup) else {
+                continue;
+            };
+            let previous = self
+                .states
+                .insert(code.trim().to_lowercase(), state.clone());
+            let Some(previous) = previous else {
+                continue;
+            };
+            let mut push = |change| {
+                changes.push(CreatorCodeChange {
+                    code: code.clone(),
+                    change,
+                })
+            };
+            match (previous, state) {
+                (
+                    CreatorCodeState::Found {
+                        status: old_status,
+                        verified: old_verified,
+                        account: old_account,
+                    },
+                    CreatorCodeState::Found {
+                        status,
+                        verified,
+                        account,
+                    },
+                ) => {
+                    if old_status != status {
+                        push(CreatorCodeChangeKind::StatusChanged {
+                            from: old_status,
+                            to: status,
+                        });
+                    }
+                    if old_account.id != account.id {
+                        push(CreatorCodeChangeKind::AccountChanged {
+                            from: old_account,
+                            to: account,
+                        });
+                    }
+                    if old_verified != verified {
+                        push(CreatorCodeChangeKind::VerifiedChanged { verified });
+                    }
+                }
+                (CreatorCodeState::Found { .. }, CreatorCodeState::NotFound) => {
+                    push(CreatorCodeChangeKind::Removed)
+                }
+                (CreatorCodeState::NotFound, CreatorCodeState::Found { .. }) => {
+                    push(CreatorCodeChangeKind::Created)
+                }
+                (CreatorCodeState::NotFound, CreatorCodeState::NotFound) => {}
+            }
+        }
+        changes
+    }
+}
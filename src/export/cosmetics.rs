@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::export::{parse_column, ExportColumn, ExportRecord, ExportValue};
+use crate::response_types::cosmetics::CosmeticV2;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CosmeticColumn {
+    Id,
+    Name,
+    Description,
+    Type,
+    TypeBackendValue,
+    Rarity,
+    RarityBackendValue,
+    Series,
+    Set,
+    SetText,
+    IntroductionChapter,
+    IntroductionSeason,
+    IntroductionText,
+    Added,
+    ShopHistoryCount,
+    LastSeen,
+}
+
+impl CosmeticColumn {
+    pub const ALL: [CosmeticColumn; 16] = [
+        CosmeticColumn::Id,
+        CosmeticColumn::Name,
+        CosmeticColumn::Description,
+        CosmeticColumn::Type,
+        CosmeticColumn::TypeBackendValue,
+        CosmeticColumn::Rarity,
+        CosmeticColumn::RarityBackendValue,
+        CosmeticColumn::Series,
+        CosmeticColumn::Set,
+        CosmeticColumn::SetText,
+        CosmeticColumn::IntroductionChapter,
+        CosmeticColumn::IntroductionSeason,
+        CosmeticColumn::IntroductionText,
+        CosmeticColumn::Added,
+        CosmeticColumn::ShopHistoryCount,
+        CosmeticColumn::LastSeen,
+    ];
+}
+
+impl ExportColumn for CosmeticColumn {
+    fn name(&self) -> &'static str {
+        match self {
+            CosmeticColumn::Id => "id",
+            CosmeticColumn::Name => "name",
+            CosmeticColumn::Description => "description",
+            CosmeticColumn::Type => "type",
+            CosmeticColumn::TypeBackendValue => "type_backend_value",
+            CosmeticColumn::Rarity => "rarity",
+            CosmeticColumn::RarityBackendValue => "rarity_backend_value",
+            CosmeticColumn::Series => "series",
+            CosmeticColumn::Set => "set",
+            CosmeticColumn::SetText => "set_text",
+            CosmeticColumn::IntroductionChapter => "introduction_chapter",
+            CosmeticColumn::IntroductionSeason => "introduction_season",
+            CosmeticColumn::IntroductionText => "introduction_text",
+            CosmeticColumn::Added => "added",
+            CosmeticColumn::ShopHistoryCount => "shop_history_count",
+            CosmeticColumn::LastSeen => "last_seen",
+        }
+    }
+}
+
+impl FromStr for CosmeticColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_column(&CosmeticColumn::ALL, s)
+    }
+}
+
+impl ExportRecord for CosmeticV2 {
+    type Column = CosmeticColumn;
+
+    fn default_columns() -> &'static [CosmeticColumn] {
+        &CosmeticColumn::ALL
+    }
+
+    fn value(&self, column: CosmeticColumn) -> ExportValue {
+        let introduction = self.introduction.as_ref();
+        match column {
+            CosmeticColumn::Id => ExportValue::Text(self.id.clone()),
+            CosmeticColumn::Name => ExportValue::Text(self.name.clone()),
+            CosmeticColumn::Description => ExportValue::Text(self.description.clone()),
+            CosmeticColumn::Type => ExportValue::Text(self.r#type.value.clone()),
+            CosmeticColumn::TypeBackendValue => {
+                ExportValue::Text(self.r#type.backend_value.clone())
+            }
+            CosmeticColumn::Rarity => ExportValue::Text(self.rarity.value.clone()),
+            CosmeticColumn::RarityBackendValue => {
+                ExportValue::Text(self.rarity.backend_value.clone())
+            }
+            CosmeticColumn::Series => self.series.as_ref().map(|s| s.value.clone()).into(),
+            CosmeticColumn::Set => self.set.as_ref().and_then(|s| s.value.clone()).into(),
+            CosmeticColumn::SetText => self.set.as_ref().and_then(|s| s.text.clone()).into(),
+            CosmeticColumn::IntroductionChapter => introduction.map(|i| i.chapter.clone()).into(),
+            CosmeticColumn::IntroductionSeason => introduction.map(|i| i.season.clone()).into(),
+            CosmeticColumn::IntroductionText => introduction.map(|i| i.text.clone()).into(),
+            CosmeticColumn::Added => ExportValue::Date(self.added),
+            CosmeticColumn::ShopHistoryCount => {
+                let count = self.shop_history.as_ref().map_or(0, Vec::len);
+                ExportValue::Integer(count as i64)
+            }
+            CosmeticColumn::LastSeen => self
+                .shop_history
+                .as_ref()
+                .and_then(|history| history.iter().max())
+                .map_or(ExportValue::Null, |date| ExportValue::Date(*date)),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Flat CSV and newline-delimited JSON exports of cosmetics and shop entries.
+//!
+//! Each exportable record has a column enum listing every available column in a stable
+//! order. Pass a subset of the columns to select and order them, or
+//! [`ExportRecord::default_columns`] to export everything. Requires the `export` feature.
+
+use std::fmt::Display;
+use std::io::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+mod cosmetics;
+mod shop;
+
+pub use cosmetics::CosmeticColumn;
+pub use shop::ShopEntryColumn;
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(std::io::Error),
+    Csv(csv::Error),
+    Json(serde_json::Error),
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::Io(error) => write!(f, "failed to write export: {error}"),
+            ExportError::Csv(error) => write!(f, "failed to write CSV: {error}"),
+            ExportError::Json(error) => write!(f, "failed to write JSON: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::Io(error) => Some(error),
+            ExportError::Csv(error) => Some(error),
+            ExportError::Json(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for ExportError {
+    fn from(error: std::io::Error) -> Self {
+        ExportError::Io(error)
+    }
+}
+
+impl From<csv::Error> for ExportError {
+    fn from(error: csv::Error) -> Self {
+        ExportError::Csv(error)
+    }
+}
+
+impl From<serde_json::Error> for ExportError {
+    fn from(error: serde_json::Error) -> Self {
+        ExportError::Json(error)
+    }
+}
+
+/// A single flattened value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportValue {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Date(DateTime<Utc>),
+}
+
+impl ExportValue {
+    fn to_csv_field(&self) -> String {
+        match self {
+            ExportValue::Null => String::new(),
+            ExportValue::Bool(value) => value.to_string(),
+            ExportValue::Integer(value) => value.to_string(),
+            ExportValue::Float(value) => value.to_string(),
+            ExportValue::Text(value) => value.clone(),
+            ExportValue::Date(value) => value.to_rfc3339_opts(SecondsFormat::Secs, true),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            ExportValue::Null => serde_json::Value::Null,
+            ExportValue::Bool(value) => (*value).into(),
+            ExportValue::Integer(value) => (*value).into(),
+            ExportValue::Float(value) => (*value).into(),
+            ExportValue::Text(value) => value.as_str().into(),
+            ExportValue::Date(value) => value.to_rfc3339_opts(SecondsFormat::Secs, true).into(),
+        }
+    }
+}
+
+impl From<Option<String>> for ExportValue {
+    fn from(value: Option<String>) -> Self {
+        value.map_or(ExportValue::Null, ExportValue::Text)
+    }
+}
+
+/// A column of an [`ExportRecord`].
+pub trait ExportColumn: Copy + 'static {
+    /// The header of the column in CSV exports and its key in JSON exports.
+    fn name(&self) -> &'static str;
+}
+
+/// A type that can be flattened into a row of [`ExportValue`]s.
+pub trait ExportRecord {
+    type Column: ExportColumn;
+
+    /// All columns in their stable default order.
+    fn default_columns() -> &'static [Self::Column];
+
+    fn value(&self, column: Self::Column) -> ExportValue;
+}
+
+pub fn write_csv<'a, R: ExportRecord + 'a, W: Write>(
+    writer: W,
+    records: impl IntoIterator<Item = &'a R>,
+    columns: &[R::Column],
+) -> Result<(), ExportError> {
+    //! Write records as CSV with a header row.
+    //!
+    //! ## Parameters
+    //!
+    //! - `writer`: Where the CSV is written to.
+    //! - `records`: The records to export, one row each.
+    //! - `columns`: The columns to export, in order.
+    //!
+    //! ## Example
+    //!
+    //! ```rust
+    //! use fortnite_api::export::{write_csv, CosmeticColumn};
+    //! use fortnite_api::response_types::cosmetics::CosmeticsV2;
+    //!
+    //! let cosmetics: CosmeticsV2 = vec![];
+    //! let mut output = Vec::new();
+    //! write_csv(&mut output, &cosmetics, &[CosmeticColumn::Id, CosmeticColumn::Name]).unwrap();
+    //! assert_eq!(String::from_utf8(output).unwrap(), "id,name\n");
+    //! ```
+    let mut writer = csv::Writer::from_writer(writer);
+    writer.write_record(columns.iter().map(ExportColumn::name))?;
+    for record in records {
+        writer.write_record(
+            columns
+                .iter()
+                .map(|column| record.value(*column).to_csv_field()),
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_ndjson<'a, R: ExportRecord + 'a, W: Write>(
+    mut writer: W,
+    records: impl IntoIterator<Item = &'a R>,
+    columns: &[R::Column],
+) -> Result<(), ExportError> {
+    //! Write records as newline-delimited JSON, one object per line.
+    //!
+    //! Object keys appear in the order of `columns`.
+    for record in records {
+        let mut line = String::from("{");
+        for (index, column) in columns.iter().enumerate() {
+            if index > 0 {
+                line.push(',');
+            }
+            line.push_str(&serde_json::to_string(column.name())?);
+            line.push(':');
+            line.push_str(&serde_json::to_string(&record.value(*column).to_json())?);
+        }
+        line.push_str("}\n");
+        writer.write_all(line.as_bytes())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub(crate) fn parse_column<C: ExportColumn>(
+    columns: &'static [C],
+    name: &str,
+) -> Result<C, String> {
+    columns
+        .iter()
+        .find(|column| column.name().eq_ignore_ascii_case(name.trim()))
+        .copied()
+        .ok_or_else(|| format!("unknown column: {name}"))
+}
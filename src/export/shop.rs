@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::export::{parse_column, ExportColumn, ExportRecord, ExportValue};
+use crate::response_types::shop::ShopFeaturedEntry;
+
+/// Columns of a shop entry. Multiple items are joined with `|`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ShopEntryColumn {
+    OfferId,
+    DevName,
+    BundleName,
+    ItemIds,
+    ItemNames,
+    ItemCount,
+    RegularPrice,
+    FinalPrice,
+    Discount,
+    SectionId,
+    Layout,
+    LayoutIndex,
+    SortPriority,
+    TileSize,
+    Banner,
+    Giftable,
+    Refundable,
+}
+
+impl ShopEntryColumn {
+    pub const ALL: [ShopEntryColumn; 17] = [
+        ShopEntryColumn::OfferId,
+        ShopEntryColumn::DevName,
+        ShopEntryColumn::BundleName,
+        ShopEntryColumn::ItemIds,
+        ShopEntryColumn::ItemNames,
+        ShopEntryColumn::ItemCount,
+        ShopEntryColumn::RegularPrice,
+        ShopEntryColumn::FinalPrice,
+        ShopEntryColumn::Discount,
+        ShopEntryColumn::SectionId,
+        ShopEntryColumn::Layout,
+        ShopEntryColumn::LayoutIndex,
+        ShopEntryColumn::SortPriority,
+        ShopEntryColumn::TileSize,
+        ShopEntryColumn::Banner,
+        ShopEntryColumn::Giftable,
+        ShopEntryColumn::Refundable,
+    ];
+}
+
+impl ExportColumn for ShopEntryColumn {
+    fn name(&self) -> &'static str {
+        match self {
+            ShopEntryColumn::OfferId => "offer_id",
+            ShopEntryColumn::DevName => "dev_name",
+            ShopEntryColumn::BundleName => "bundle_name",
+            ShopEntryColumn::ItemIds => "item_ids",
+            ShopEntryColumn::ItemNames => "item_names",
+            ShopEntryColumn::ItemCount => "item_count",
+            ShopEntryColumn::RegularPrice => "regular_price",
+            ShopEntryColumn::FinalPrice => "final_price",
+            ShopEntryColumn::Discount => "discount",
+            ShopEntryColumn::SectionId => "section_id",
+            ShopEntryColumn::Layout => "layout",
+            ShopEntryColumn::LayoutIndex => "layout_index",
+            ShopEntryColumn::SortPriority => "sort_priority",
+            ShopEntryColumn::TileSize => "tile_size",
+            ShopEntryColumn::Banner => "banner",
+            ShopEntryColumn::Giftable => "giftable",
+            ShopEntryColumn::Refundable => "refundable",
+        }
+    }
+}
+
+impl FromStr for ShopEntryColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_column(&ShopEntryColumn::ALL, s)
+    }
+}
+
+impl ExportRecord for ShopFeaturedEntry {
+    type Column = ShopEntryColumn;
+
+    fn default_columns() -> &'static [ShopEntryColumn] {
+        &ShopEntryColumn::ALL
+    }
+
+    fn value(&self, column: ShopEntryColumn) -> ExportValue {
+        let join = |field: fn(&crate::response_types::cosmetics::CosmeticV2) -> &str| {
+            let values = self.items.iter().map(field).collect::<Vec<_>>();
+            ExportValue::Text(values.join("|"))
+        };
+        match column {
+            ShopEntryColumn::OfferId => ExportValue::Text(self.offer_id.clone()),
+            ShopEntryColumn::DevName => ExportValue::Text(self.dev_name.clone()),
+            ShopEntryColumn::BundleName => self.bundle.as_ref().map(|b| b.name.clone()).into(),
+            ShopEntryColumn::ItemIds => join(|item| &item.id),
+            ShopEntryColumn::ItemNames => join(|item| &item.name),
+            ShopEntryColumn::ItemCount => ExportValue::Integer(self.items.len() as i64),
+            ShopEntryColumn::RegularPrice => ExportValue::Integer(self.regular_price as i64),
+            ShopEntryColumn::FinalPrice => ExportValue::Integer(self.final_price as i64),
+            ShopEntryColumn::Discount => {
+                let discount = self.regular_price.saturating_sub(self.final_price);
+                ExportValue::Integer(discount as i64)
+            }
+            ShopEntryColumn::SectionId => ExportValue::Text(self.section_id.clone()),
+            ShopEntryColumn::Layout => ExportValue::Text(self.layout.name.clone()),
+            ShopEntryColumn::LayoutIndex => ExportValue::Integer(self.layout.index),
+            ShopEntryColumn::SortPriority => ExportValue::Integer(self.sort_priority),
+            ShopEntryColumn::TileSize => ExportValue::Text(self.tile_size.clone()),
+            ShopEntryColumn::Banner => self.banner.as_ref().map(|b| b.value.clone()).into(),
+            ShopEntryColumn::Giftable => ExportValue::Bool(self.giftable),
+            ShopEntryColumn::Refundable => ExportValue::Bool(self.refundable),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+//! Languages of the API and fetching resources in several languages at once.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::str::FromStr;
+
+use futures_util::{stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::response_types::cosmetics::CosmeticsV2;
+use crate::response_types::news::{News, NewsV2};
+use crate::response_types::playlists::PlaylistsV1;
+
+/// A language of the API's localized texts, passed to endpoints with [`Language::code`].
+///
+/// ## Example
+///
+/// ```rust
+/// use fortnite_api::languages::Language;
+///
+/// assert_eq!("pt-br".parse::<Language>().unwrap(), Language::PortugueseBrazil);
+/// assert_eq!(Language::ChineseSimplified.code(), "zh-CN");
+/// assert_eq!("nl".parse::<Language>().unwrap(), Language::Other("nl".to_string()));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub enum Language {
+    Arabic,
+    German,
+    #[default]
+    English,
+    Spanish,
+    SpanishLatinAmerica,
+    French,
+    Indonesian,
+    Italian,
+    Japanese,
+    Korean,
+    Polish,
+    PortugueseBrazil,
+    Russian,
+    Thai,
+    Turkish,
+    Vietnamese,
+    ChineseSimplified,
+    ChineseTraditional,
+    /// A language this version of the crate doesn't know, with its code.
+    Other(String),
+}
+
+impl Language {
+    pub const ALL: [Language; 18] = [
+        Language::Arabic,
+        Language::German,
+        Language::English,
+        Language::Spanish,
+        Language::SpanishLatinAmerica,
+        Language::French,
+        Language::Indonesian,
+        Language::Italian,
+        Language::Japanese,
+        Language::Korean,
+        Language::Polish,
+        Language::PortugueseBrazil,
+        Language::Russian,
+        Language::Thai,
+        Language::Turkish,
+        Language::Vietnamese,
+        Language::ChineseSimplified,
+        Language::ChineseTraditional,
+    ];
+
+    pub fn code(&self) -> &str {
+        //! The code the API expects in the `language` parameter.
+        match self {
+            Language::Arabic => "ar",
+            Language::German => "de",
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::SpanishLatinAmerica => "es-419",
+            Language::French => "fr",
+            Language::Indonesian => "id",
+            Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Polish => "pl",
+            Language::PortugueseBrazil => "pt-BR",
+            Language::Russian => "ru",
+            Language::Thai => "th",
+            Language::Turkish => "tr",
+            Language::Vietnamese => "vi",
+            Language::ChineseSimplified => "zh-CN",
+            Language::ChineseTraditional => "zh-Hant",
+            Language::Other(code) => code,
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        //! Whether the language is known to be supported by the API.
+        !matches!(self, Language::Other(_))
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    /// Parse a language code, ignoring case. Unknown codes become [`Language::Other`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim();
+        if code.is_empty() {
+            return Err("empty language code".to_string());
+        }
+        Ok(Language::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+            .unwrap_or_else(|| Language::Other(code.to_string())))
+    }
+}
+
+impl Serialize for Language {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// The number of languages [`fetch_localized_cosmetics`], [`fetch_localized_playlists`] and
+/// [`fetch_localized_news`] request at once. Use [`fetch_languages`] with [`merge_localized`]
+/// to pick another limit.
+pub const LOCALIZED_CONCURRENCY: usize = 3;
+
+/// The name and description of a resource in every fetched language.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedText {
+    pub name: BTreeMap<Language, String>,
+    pub description: BTreeMap<Language, String>,
+}
+
+/// Localized texts keyed by resource ID.
+pub type LocalizedTexts = BTreeMap<String, LocalizedText>;
+
+/// Responses with localized names and descriptions of resources with an ID.
+pub trait Localizable {
+    /// The ID, name and description of every localized resource of the response.
+    fn localized_texts(&self) -> Vec<(String, Option<String>, Option<String>)>;
+}
+
+impl Localizable for CosmeticsV2 {
+    fn localized_texts(&self) -> Vec<(String, Option<String>, Option<String>)> {
+        self.iter()
+            .map(|cosmetic| {
+                (
+                    cosmetic.id.clone(),
+                    Some(cosmetic.name.clone()),
+                    Some(cosmetic.description.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Localizable for PlaylistsV1 {
+    fn localized_texts(&self) -> Vec<(String, Option<String>, Option<String>)> {
+        self.iter()
+            .map(|playlist| {
+                (
+                    playlist.id.clone(),
+                    playlist.name.clone(),
+                    playlist.description.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Localizable for NewsV2 {
+    /// The title and body of every MOTD. News messages have no ID and are left out.
+    fn localized_texts(&self) -> Vec<(String, Option<String>, Option<String>)> {
+        [&self.br, &self.stw, &self.creative]
+            .into_iter()
+            .flatten()
+            .filter_map(|news| match news {
+                News::NewsContent(content) => content.motds.as_ref(),
+                News::NoNews => None,
+            })
+            .flatten()
+            .map(|motd| {
+                (
+                    motd.id.clone(),
+                    Some(motd.title.clone()),
+                    Some(motd.body.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+pub fn merge_localized<T: Localizable>(responses: &[(Language, T)]) -> LocalizedTexts {
+    //! Merge the same response in several languages into per-ID texts. Empty texts are
+    //! left out.
+    let mut texts = LocalizedTexts::new();
+    for (language, response) in responses {
+        for (id, name, description) in response.localized_texts() {
+            let text = texts.entry(id).or_default();
+            if let Some(name) = name.filter(|name| !name.is_empty()) {
+                text.name.insert(language.clone(), name);
+            }
+            if let Some(description) = description.filter(|description| !description.is_empty()) {
+                text.description.insert(language.clone(), description);
+            }
+        }
+    }
+    texts
+}
+
+pub async fn fetch_languages<T, F, Fut>(
+    languages: &[Language],
+    concurrency: usize,
+    fetch: F,
+) -> reqwest::Result<Vec<(Language, T)>>
+where
+    F: Fn(Language) -> Fut,
+    Fut: Future<Output = reqwest::Result<T>>,
+{
+    //! Fetch a resource in several languages concurrently.
+    //!
+    //! ## Parameters
+    //!
+    //! - `languages`: The languages to fetch.
+    //! - `concurrency`: The maximum number of simultaneous requests.
+    //! - `fetch`: Fetches the resource in one language.
+    //!
+    //! ## Returns
+    //!
+    //! The responses in the order of `languages`, or the first error.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::languages::{fetch_languages, merge_localized, Language};
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!     let languages = [Language::English, Language::German, Language::Japanese];
+    //!
+    //!     let responses = fetch_languages(&languages, 3, |language| {
+    //!         let http_client = http_client.clone();
+    //!         async move { fortnite_api::get_playlists_v1(&http_client, Some(language.code())).await }
+    //!     })
+    //!     .await
+    //!     .unwrap();
+    //!     let texts = merge_localized(&responses);
+    //!     println!("{texts:#?}");
+    //! }
+    //! ```
+    stream::iter(languages.iter().cloned())
+        .map(|language| {
+            let response = fetch(language.clone());
+            async move { response.await.map(|response| (language, response)) }
+        })
+        .buffered(concurrency.max(1))
+        .try_collect()
+        .await
+}
+
+pub async fn fetch_localized_cosmetics(
+    http_client: &reqwest::Client,
+    languages: &[Language],
+) -> reqwest::Result<LocalizedTexts> {
+    //! Fetch all cosmetics in `languages` and merge their names and descriptions.
+    //! Fetches [`LOCALIZED_CONCURRENCY`] languages at once.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::languages::{fetch_localized_cosmetics, Language};
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!     let texts = fetch_localized_cosmetics(&http_client, &[Language::English, Language::French])
+    //!         .await
+    //!         .unwrap();
+    //!     let raider = &texts["CID_028_Athena_Commando_F"];
+    //!     println!("{:?}", raider.name.get(&Language::French));
+    //! }
+    //! ```
+    let responses = fetch_languages(languages, LOCALIZED_CONCURRENCY, |language| async move {
+        crate::get_cosmetics_v2(http_client, Some(language.code())).await
+    })
+    .await?;
+    Ok(merge_localized(&responses))
+}
+
+pub async fn fetch_localized_playlists(
+    http_client: &reqwest::Client,
+    languages: &[Language],
+) -> reqwest::Result<LocalizedTexts> {
+    //! Fetch all playlists in `languages` and merge their names and descriptions.
+    //! Fetches [`LOCALIZED_CONCURRENCY`] languages at once.
+    let responses = fetch_languages(languages, LOCALIZED_CONCURRENCY, |language| async move {
+        crate::get_playlists_v1(http_client, Some(language.code())).await
+    })
+    .await?;
+    Ok(merge_localized(&responses))
+}
+
+pub async fn fetch_localized_news(
+    http_client: &reqwest::Client,
+    languages: &[Language],
+) -> reqwest::Result<LocalizedTexts> {
+    //! Fetch the news in `languages` and merge the titles and bodies of their MOTDs.
+    //! Fetches [`LOCALIZED_CONCURRENCY`] languages at once.
+    let responses = fetch_languages(languages, LOCALIZED_CONCURRENCY, |language| async move {
+        crate::get_news_v2(http_client, Some(language.code())).await
+    })
+    .await?;
+    Ok(merge_localized(&responses))
+}
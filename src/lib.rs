@@ -44,6 +44,16 @@
 //! | Playlists By ID V1 | [`get_playlist_by_id_v1`] | [`PlaylistV1`] |
 //! | Shop BR V2 | [`get_shop_br_v2`] [`get_shop_combined_v2`] | [`ShopV2`] |
 //! | Stats V2 | [`get_stats_v2`] [`get_stats_by_account_id_v2`] | [`StatsV2`] |
+//!
+//! ## Features
+//!
+//! | Feature | Description |
+//! | --- | --- |
+//! | `assets` | Collecting the image URLs of responses and downloading them into a verified local cache (`assets`). |
+//! | `cli` | The `fortnite-api` command-line binary with a subcommand per endpoint. |
+//! | `export` | CSV and newline-delimited JSON exports of cosmetics and shop entries (`export`). |
+//! | `render` | Offline image rendering of annotated maps (`render::map`), shop graphics (`render::shop`), stats cards (`render::stats`) and player banners (`render::banner`). |
+//! | `sqlite` | Persistence of shop, news, playlist, AES, new cosmetics and stats snapshots in a SQLite database (`storage`). |
 
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -59,9 +69,26 @@ use crate::response_types::news::{News, NewsV2};
 use crate::response_types::playlists::{PlaylistV1, PlaylistsV1};
 use crate::response_types::shop::ShopV2;
 use crate::response_types::stats::{StatsAccountType, StatsImage, StatsTimeWindow, StatsV2};
+use crate::stats::StatsQuery;
 use crate::utils::fetch::fetch_endpoint;
 
+pub mod aes;
+#[cfg(feature = "assets")]
+pub mod assets;
+pub mod banners;
+pub mod cosmetics;
+pub mod creator_codes;
+#[cfg(feature = "export")]
+pub mod export;
+pub mod languages;
+#[cfg(feature = "render")]
+pub mod render;
 pub mod response_types;
+pub mod seasons;
+pub mod shop;
+pub mod stats;
+#[cfg(feature = "sqlite")]
+pub mod storage;
 pub mod utils;
 
 pub async fn get_aes_keys_v2(
@@ -111,7 +138,7 @@ pub async fn get_banners_v1(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the banners. Can be `None` or a language code.
+    //! - `language`: The language of the banners. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -176,7 +203,7 @@ pub async fn get_cosmetics_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the cosmetics. Can be `None` or a language code.
+    //! - `language`: The language of the cosmetics. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -210,7 +237,7 @@ pub async fn get_cosmetics_new_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the cosmetics. Can be `None` or a language code.
+    //! - `language`: The language of the cosmetics. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -246,7 +273,7 @@ pub async fn get_cosmetic_by_id_v2(
     //!
     //! - `http_client`: The reqwest client.
     //! - `cosmetic_id`: The ID of the cosmetic.
-    //! - `language`: The language of the cosmetic. Can be `None` or a language code.
+    //! - `language`: The language of the cosmetic. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -320,7 +347,7 @@ pub async fn get_map_v1(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the map. Can be `None` or a language code.
+    //! - `language`: The language of the map. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -355,7 +382,7 @@ pub async fn get_news_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the news. Can be `None` or a language code.
+    //! - `language`: The language of the news. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -389,7 +416,7 @@ pub async fn get_news_br_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the news. Can be `None` or a language code.
+    //! - `language`: The language of the news. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -423,7 +450,7 @@ pub async fn get_news_stw_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the news. Can be `None` or a language code.
+    //! - `language`: The language of the news. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -457,7 +484,7 @@ pub async fn get_news_creative_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the news. Can be `None` or a language code.
+    //! - `language`: The language of the news. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -491,7 +518,7 @@ pub async fn get_playlists_v1(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the playlists. Can be `None` or a language code.
+    //! - `language`: The language of the playlists. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -527,7 +554,7 @@ pub async fn get_playlist_by_id_v1(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the playlists. Can be `None` or a language code.
+    //! - `language`: The language of the playlists. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -568,7 +595,7 @@ pub async fn get_shop_br_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the shop. Can be `None` or a language code.
+    //! - `language`: The language of the shop. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -603,7 +630,7 @@ pub async fn get_shop_combined_v2(
     //! ## Parameters
     //!
     //! - `http_client`: The reqwest client.
-    //! - `language`: The language of the shop. Can be `None` or a language code.
+    //! - `language`: The language of the shop. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
@@ -647,11 +674,12 @@ pub async fn get_stats_v2(
     //! - `account_type`: The account type of the player. Can be `None` or [`StatsAccountType`].
     //! - `time_window`: The time window of the stats. Can be `None` or [`StatsTimeWindow`].
     //! - `image`: The image of the stats. Can be `None` or [`StatsImage`].
-    //! - `language`: The language of the shop. Can be `None` or a language code.
+    //! - `language`: The language of the shop. Can be `None` or a language code, see [`languages::Language`].
     //!
     //! ## Returns
     //!
-    //! The player stats.
+    //! The player stats. See [`stats::StatsQuery`] for requests that are validated before
+    //! they are sent.
     //!
     //! ## Example
     //!
@@ -671,20 +699,17 @@ pub async fn get_stats_v2(
     //!     assert!(result.is_ok());
     //! }
     //! ```
-    let mut url = Url::from_str("https://fortnite-api.com/v2/stats/br/v2").unwrap();
-    url.query_pairs_mut().append_pair("name", name);
+    let mut query = StatsQuery::by_name(name);
     if let Some(account_type) = account_type {
-        url.query_pairs_mut()
-            .append_pair("accountType", &account_type.to_string().to_lowercase());
+        query = query.with_account_type(account_type);
     }
     if let Some(time_window) = time_window {
-        url.query_pairs_mut()
-            .append_pair("timeWindow", &time_window.to_string().to_lowercase());
+        query = query.with_time_window(time_window);
     }
     if let Some(image) = image {
-        url.query_pairs_mut()
-            .append_pair("image", &image.to_string().to_lowercase());
+        query = query.with_image(image);
     }
+    let url = query.build_url();
     let headers = [("Authorization".to_string(), api_key)]
         .into_iter()
         .collect();
@@ -711,7 +736,8 @@ pub async fn get_stats_by_account_id_v2(
     //!
     //! ## Returns
     //!
-    //! The player stats.
+    //! The player stats. See [`stats::StatsQuery`] for requests that are validated before
+    //! they are sent.
     //!
     //! ## Example
     //!
@@ -737,17 +763,14 @@ pub async fn get_stats_by_account_id_v2(
     //!     assert!(result.is_ok());
     //! }
     //! ```
-    let mut url =
-        Url::from_str(format!("https://fortnite-api.com/v2/stats/br/v2/{account_id}").as_str())
-            .unwrap();
+    let mut query = StatsQuery::by_account_id(account_id);
     if let Some(time_window) = time_window {
-        url.query_pairs_mut()
-            .append_pair("timeWindow", &time_window.to_string().to_lowercase());
+        query = query.with_time_window(time_window);
     }
     if let Some(image) = image {
-        url.query_pairs_mut()
-            .append_pair("image", &image.to_string().to_lowercase());
+        query = query.with_image(image);
     }
+    let url = query.build_url();
     let headers = [("Authorization".to_string(), api_key)]
         .into_iter()
         .collect();
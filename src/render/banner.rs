@@ -0,0 +1,98 @@
+use tiny_skia::{Color, PremultipliedColorU8, Rect};
+
+use crate::render::{color_from_hex, decode_png, draw_image, encode_png, new_canvas, RenderError};
+use crate::response_types::banners::BannersColorV1;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BannerRenderOptions {
+    /// Edge length of the square output image in pixels.
+    pub size: u32,
+    /// Size of the icon relative to the output size.
+    pub icon_scale: f32,
+    /// Color the icon is tinted with, keeping its transparency. `None` keeps the icon colors.
+    pub tint: Option<Color>,
+}
+
+impl Default for BannerRenderOptions {
+    fn default() -> Self {
+        BannerRenderOptions {
+            size: 256,
+            icon_scale: 0.85,
+            tint: None,
+        }
+    }
+}
+
+pub fn render_banner(
+    icon_png: &[u8],
+    color: &BannersColorV1,
+    options: &BannerRenderOptions,
+) -> Result<Vec<u8>, RenderError> {
+    //! Render a player banner, i.e. a banner icon on top of a banner color.
+    //!
+    //! ## Parameters
+    //!
+    //! - `icon_png`: The downloaded PNG of a [`BannerImages`](crate::response_types::banners::BannerImages) icon.
+    //! - `color`: The background color, e.g. looked up via [`BannerColorPalette`](crate::banners::BannerColorPalette).
+    //! - `options`: The output size and an optional tint for the icon.
+    //!
+    //! ## Returns
+    //!
+    //! The banner as PNG bytes.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::banners::BannerColorPalette;
+    //! use fortnite_api::render::banner::{render_banner, BannerRenderOptions};
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!
+    //!     let banners = fortnite_api::get_banners_v1(&http_client, None).await.unwrap();
+    //!     let colors = fortnite_api::get_banners_colors_v1(&http_client).await.unwrap();
+    //!     let palette = BannerColorPalette::new(colors);
+    //!
+    //!     let banner = banners.first().unwrap();
+    //!     let icon = http_client.get(&banner.images.icon).send().await.unwrap();
+    //!     let icon = icon.bytes().await.unwrap();
+    //!     let color = palette.colors().first().unwrap();
+    //!
+    //!     let options = BannerRenderOptions {
+    //!         size: 512,
+    //!         ..BannerRenderOptions::default()
+    //!     };
+    //!     let result = render_banner(&icon, color, &options);
+    //!     assert!(result.is_ok());
+    //! }
+    //! ```
+    let background = color_from_hex(&color.color)?;
+    let mut icon = decode_png(icon_png)?;
+    if let Some(tint) = options.tint {
+        let tint = tint.to_color_u8();
+        for pixel in icon.pixels_mut() {
+            let alpha = pixel.alpha();
+            let channel = |value: u8| ((u16::from(value) * u16::from(alpha)) / 255) as u8;
+            if let Some(tinted) = PremultipliedColorU8::from_rgba(
+                channel(tint.red()),
+                channel(tint.green()),
+                channel(tint.blue()),
+                alpha,
+            ) {
+                *pixel = tinted;
+            }
+        }
+    }
+
+    let mut pixmap = new_canvas(options.size, options.size)?;
+    pixmap.fill(background);
+    let size = options.size as f32;
+    let icon_size = size * options.icon_scale.clamp(0.0, 1.0);
+    let offset = (size - icon_size) / 2.0;
+    if let Some(target) = Rect::from_xywh(offset, offset, icon_size, icon_size) {
+        draw_image(&mut pixmap, &icon, target);
+    }
+
+    encode_png(&pixmap)
+}
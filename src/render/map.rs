@@ -0,0 +1,262 @@
+use tiny_skia::{
+    Color, FillRule, GradientStop, LineCap, LineJoin, PathBuilder, Point, RadialGradient,
+    SpreadMode, Stroke, Transform,
+};
+
+use crate::render::{
+    circle, decode_png, draw_text, encode_png, solid_paint, FontArc, RenderError, TextAlign,
+    TextStyle,
+};
+use crate::response_types::map::{MapPoiLocation, MapV1};
+
+/// Converts in-game world coordinates to pixel coordinates on the map image.
+///
+/// Fortnite uses the world `x` axis for north/south and `y` for east/west, so `y` maps to the
+/// horizontal image axis and `x` to the (inverted) vertical one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapProjection {
+    /// Half the edge length of the square area covered by the map image, in world units.
+    pub world_radius: f64,
+    /// World `x` coordinate of the image center.
+    pub center_x: f64,
+    /// World `y` coordinate of the image center.
+    pub center_y: f64,
+}
+
+impl Default for MapProjection {
+    fn default() -> Self {
+        MapProjection {
+            world_radius: 135_000.0,
+            center_x: 0.0,
+            center_y: 0.0,
+        }
+    }
+}
+
+impl MapProjection {
+    pub fn project(&self, location: &MapPoiLocation, width: u32, height: u32) -> (f32, f32) {
+        //! Project a world location onto an image of the given size.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::render::map::MapProjection;
+        //! use fortnite_api::response_types::map::MapPoiLocation;
+        //!
+        //! let center = MapPoiLocation { x: 0.0, y: 0.0, z: 0.0 };
+        //! assert_eq!(MapProjection::default().project(&center, 2048, 2048), (1024.0, 1024.0));
+        //! ```
+        let diameter = self.world_radius * 2.0;
+        let horizontal = (location.y - self.center_y + self.world_radius) / diameter;
+        let vertical = 1.0 - (location.x - self.center_x + self.world_radius) / diameter;
+        (
+            (horizontal * f64::from(width)) as f32,
+            (vertical * f64::from(height)) as f32,
+        )
+    }
+}
+
+/// A custom point of interest drawn on top of the map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapMarker {
+    pub location: MapPoiLocation,
+    pub label: Option<String>,
+    pub color: Color,
+    pub radius: f32,
+}
+
+/// A polyline through world locations, e.g. a drop path or a storm rotation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapPath {
+    pub points: Vec<MapPoiLocation>,
+    pub color: Color,
+    pub width: f32,
+}
+
+/// A single heatmap sample. `weight` is clamped to `0.0..=1.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapHeatPoint {
+    pub location: MapPoiLocation,
+    pub weight: f32,
+}
+
+/// Caller supplied annotations drawn in addition to the POIs of the map.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MapAnnotations {
+    pub markers: Vec<MapMarker>,
+    pub paths: Vec<MapPath>,
+    pub heat_points: Vec<MapHeatPoint>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MapRenderOptions {
+    pub projection: MapProjection,
+    /// Font used for POI and marker labels. Labels are skipped without a font.
+    pub font: Option<FontArc>,
+    pub draw_pois: bool,
+    pub poi_color: Color,
+    pub poi_radius: f32,
+    pub label_size: f32,
+    pub label_color: Color,
+    pub label_halo: Color,
+    pub heat_color: Color,
+    pub heat_radius: f32,
+}
+
+impl Default for MapRenderOptions {
+    fn default() -> Self {
+        MapRenderOptions {
+            projection: MapProjection::default(),
+            font: None,
+            draw_pois: true,
+            poi_color: Color::from_rgba8(255, 255, 255, 255),
+            poi_radius: 6.0,
+            label_size: 22.0,
+            label_color: Color::from_rgba8(255, 255, 255, 255),
+            label_halo: Color::from_rgba8(0, 0, 0, 200),
+            heat_color: Color::from_rgba8(255, 64, 0, 255),
+            heat_radius: 60.0,
+        }
+    }
+}
+
+pub fn render_map(
+    map: &MapV1,
+    blank_png: &[u8],
+    annotations: &MapAnnotations,
+    options: &MapRenderOptions,
+) -> Result<Vec<u8>, RenderError> {
+    //! Render an annotated map.
+    //!
+    //! ## Parameters
+    //!
+    //! - `map`: The map whose POIs are drawn.
+    //! - `blank_png`: The downloaded PNG behind [`MapImages::blank`](crate::response_types::map::MapImages::blank).
+    //! - `annotations`: Additional markers, paths and heatmap points.
+    //! - `options`: Styling and projection of the rendered map.
+    //!
+    //! ## Returns
+    //!
+    //! The annotated map as PNG bytes.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::render::map::{render_map, MapAnnotations, MapRenderOptions};
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!
+    //!     let map = fortnite_api::get_map_v1(&http_client, None).await.unwrap();
+    //!     let blank = http_client.get(&map.images.blank).send().await.unwrap();
+    //!     let blank = blank.bytes().await.unwrap();
+    //!
+    //!     let result = render_map(&map, &blank, &MapAnnotations::default(), &MapRenderOptions::default());
+    //!     assert!(result.is_ok());
+    //! }
+    //! ```
+    let mut pixmap = decode_png(blank_png)?;
+    let (width, height) = (pixmap.width(), pixmap.height());
+    let project = |location: &MapPoiLocation| options.projection.project(location, width, height);
+
+    for heat_point in &annotations.heat_points {
+        let (x, y) = project(&heat_point.location);
+        let mut inner = options.heat_color;
+        inner.apply_opacity(heat_point.weight.clamp(0.0, 1.0));
+        let mut outer = inner;
+        outer.set_alpha(0.0);
+        let shader = RadialGradient::new(
+            Point::from_xy(x, y),
+            0.0,
+            Point::from_xy(x, y),
+            options.heat_radius,
+            vec![GradientStop::new(0.0, inner), GradientStop::new(1.0, outer)],
+            SpreadMode::Pad,
+            Transform::identity(),
+        );
+        if let (Some(shader), Some(path)) = (shader, circle(x, y, options.heat_radius)) {
+            let mut paint = solid_paint(inner);
+            paint.shader = shader;
+            pixmap.fill_path(
+                &path,
+                &paint,
+                FillRule::Winding,
+                Transform::identity(),
+                None,
+            );
+        }
+    }
+
+    for map_path in &annotations.paths {
+        let mut builder = PathBuilder::new();
+        for (index, location) in map_path.points.iter().enumerate() {
+            let (x, y) = project(location);
+            if index == 0 {
+                builder.move_to(x, y);
+            } else {
+                builder.line_to(x, y);
+            }
+        }
+        if let Some(path) = builder.finish() {
+            let stroke = Stroke {
+                width: map_path.width,
+                line_cap: LineCap::Round,
+                line_join: LineJoin::Round,
+                ..Stroke::default()
+            };
+            let paint = solid_paint(map_path.color);
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+
+    let pois = map.pois.iter().filter(|_| options.draw_pois).map(|poi| {
+        (
+            &poi.location,
+            poi.name.as_deref(),
+            options.poi_color,
+            options.poi_radius,
+        )
+    });
+    let markers = annotations.markers.iter().map(|marker| {
+        (
+            &marker.location,
+            marker.label.as_deref(),
+            marker.color,
+            marker.radius,
+        )
+    });
+    let points = pois.chain(markers).collect::<Vec<_>>();
+
+    let outline = Stroke {
+        width: 2.0,
+        ..Stroke::default()
+    };
+    for (location, _, color, radius) in &points {
+        let (x, y) = project(location);
+        if let Some(path) = circle(x, y, *radius) {
+            let fill = solid_paint(*color);
+            pixmap.fill_path(&path, &fill, FillRule::Winding, Transform::identity(), None);
+            let halo = solid_paint(options.label_halo);
+            pixmap.stroke_path(&path, &halo, &outline, Transform::identity(), None);
+        }
+    }
+
+    if let Some(font) = &options.font {
+        let style = TextStyle {
+            size: options.label_size,
+            color: options.label_color,
+            halo: Some((options.label_halo, options.label_size / 5.0)),
+            align: TextAlign::Center,
+        };
+        for (location, label, _, radius) in &points {
+            let Some(label) = label.filter(|label| !label.is_empty()) else {
+                continue;
+            };
+            let (x, y) = project(location);
+            draw_text(&mut pixmap, font, label, x, y - radius - 6.0, &style);
+        }
+    }
+
+    encode_png(&pixmap)
+}
@@ -0,0 +1,274 @@
+//! Offline image rendering.
+//!
+//! Everything in this module is pure Rust and works on already downloaded image bytes,
+//! so no browser or network access is needed. Requires the `render` feature.
+
+use std::fmt::Display;
+
+use ab_glyph::{Font, OutlineCurve, ScaleFont};
+use tiny_skia::{
+    FillRule, FilterQuality, Paint, Path, PathBuilder, Pixmap, PixmapPaint, Rect, Stroke, Transform,
+};
+
+pub use ab_glyph::FontArc;
+pub use tiny_skia::Color;
+
+pub mod banner;
+pub mod map;
+pub mod shop;
+pub mod stats;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RenderError {
+    /// An input image could not be decoded.
+    Decode(String),
+    /// The rendered image could not be encoded.
+    Encode(String),
+    /// A color string could not be parsed.
+    InvalidColor(String),
+    /// The requested canvas size is zero or too large.
+    InvalidSize { width: u32, height: u32 },
+    /// The image needs text but no font was given.
+    MissingFont,
+    /// A grid of tiles whose canvas size doesn't fit in `u32` pixels.
+    GridTooLarge { columns: u32, rows: usize },
+}
+
+impl Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderError::Decode(error) => write!(f, "failed to decode image: {error}"),
+            RenderError::Encode(error) => write!(f, "failed to encode image: {error}"),
+            RenderError::InvalidColor(color) => write!(f, "invalid color: {color}"),
+            RenderError::InvalidSize { width, height } => {
+                write!(f, "invalid canvas size: {width}x{height}")
+            }
+            RenderError::MissingFont => write!(f, "no font to draw text with"),
+            RenderError::GridTooLarge { columns, rows } => {
+                write!(
+                    f,
+                    "a grid of {columns} columns and {rows} rows is too large"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+pub fn color_from_hex(hex: &str) -> Result<Color, RenderError> {
+    //! Parse a color as used by the Fortnite API.
+    //!
+    //! Accepts `RRGGBB` and `RRGGBBAA`, with or without a leading `#`.
+    //!
+    //! ## Example
+    //!
+    //! ```rust
+    //! use fortnite_api::render::color_from_hex;
+    //!
+    //! let color = color_from_hex("#ff800080").unwrap();
+    //! assert_eq!(color.to_color_u8().red(), 0xff);
+    //! assert_eq!(color.to_color_u8().alpha(), 0x80);
+    //! assert!(color_from_hex("not a color").is_err());
+    //! ```
+    let digits = hex.trim().trim_start_matches('#');
+    let invalid = || RenderError::InvalidColor(hex.to_string());
+    if !matches!(digits.len(), 6 | 8) || !digits.is_ascii() {
+        return Err(invalid());
+    }
+    let channel = |index: usize| {
+        digits
+            .get(index..index + 2)
+            .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+            .ok_or_else(invalid)
+    };
+    let alpha = if digits.len() == 8 { channel(6)? } else { 0xff };
+
+    Ok(Color::from_rgba8(
+        channel(0)?,
+        channel(2)?,
+        channel(4)?,
+        alpha,
+    ))
+}
+
+pub(crate) fn decode_png(data: &[u8]) -> Result<Pixmap, RenderError> {
+    Pixmap::decode_png(data).map_err(|error| RenderError::Decode(error.to_string()))
+}
+
+pub(crate) fn encode_png(pixmap: &Pixmap) -> Result<Vec<u8>, RenderError> {
+    pixmap
+        .encode_png()
+        .map_err(|error| RenderError::Encode(error.to_string()))
+}
+
+pub(crate) fn solid_paint(color: Color) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color(color);
+    paint.anti_alias = true;
+    paint
+}
+
+pub(crate) fn circle(x: f32, y: f32, radius: f32) -> Option<Path> {
+    PathBuilder::from_circle(x, y, radius)
+}
+
+pub(crate) fn new_canvas(width: u32, height: u32) -> Result<Pixmap, RenderError> {
+    Pixmap::new(width, height).ok_or(RenderError::InvalidSize { width, height })
+}
+
+pub(crate) fn draw_image(pixmap: &mut Pixmap, image: &Pixmap, target: Rect) {
+    //! Scale `image` to fit centered inside `target`, keeping its aspect ratio.
+    let (image_width, image_height) = (image.width() as f32, image.height() as f32);
+    let scale = (target.width() / image_width).min(target.height() / image_height);
+    let x = target.x() + (target.width() - image_width * scale) / 2.0;
+    let y = target.y() + (target.height() - image_height * scale) / 2.0;
+    let paint = PixmapPaint {
+        quality: FilterQuality::Bicubic,
+        ..PixmapPaint::default()
+    };
+    let transform = Transform::from_row(scale, 0.0, 0.0, scale, x, y);
+    pixmap.draw_pixmap(0, 0, image.as_ref(), &paint, transform, None);
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) enum TextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TextStyle {
+    pub size: f32,
+    pub color: Color,
+    pub halo: Option<(Color, f32)>,
+    pub align: TextAlign,
+}
+
+pub(crate) fn text_width(font: &FontArc, text: &str, size: f32) -> f32 {
+    let font = font.as_scaled(size);
+    let mut width = 0.0;
+    let mut previous = None;
+    for character in text.chars() {
+        let glyph_id = font.glyph_id(character);
+        if let Some(previous) = previous {
+            width += font.kern(previous, glyph_id);
+        }
+        width += font.h_advance(glyph_id);
+        previous = Some(glyph_id);
+    }
+    width
+}
+
+pub(crate) fn fit_text(font: &FontArc, text: &str, size: f32, max_width: f32) -> String {
+    //! Shorten `text` with an ellipsis until it fits into `max_width`.
+    if text_width(font, text, size) <= max_width {
+        return text.to_string();
+    }
+    let mut characters = text.chars().collect::<Vec<_>>();
+    while !characters.is_empty() {
+        characters.pop();
+        let shortened = format!("{}…", characters.iter().collect::<String>().trim_end());
+        if text_width(font, &shortened, size) <= max_width {
+            return shortened;
+        }
+    }
+    String::new()
+}
+
+fn text_path(font: &FontArc, text: &str, size: f32, x: f32, baseline: f32) -> Option<Path> {
+    let scaled = font.as_scaled(size);
+    let scale_x = scaled.h_scale_factor();
+    let scale_y = scaled.v_scale_factor();
+    let mut builder = PathBuilder::new();
+    let mut caret = x;
+    let mut previous = None;
+    for character in text.chars() {
+        let glyph_id = scaled.glyph_id(character);
+        if let Some(previous) = previous {
+            caret += scaled.kern(previous, glyph_id);
+        }
+        if let Some(outline) = font.outline(glyph_id) {
+            let point = |p: ab_glyph::Point| (caret + p.x * scale_x, baseline - p.y * scale_y);
+            let mut last = None;
+            for curve in &outline.curves {
+                let (start, end) = match curve {
+                    OutlineCurve::Line(p0, p1) => (*p0, *p1),
+                    OutlineCurve::Quad(p0, _, p2) => (*p0, *p2),
+                    OutlineCurve::Cubic(p0, _, _, p3) => (*p0, *p3),
+                };
+                if last != Some(start) {
+                    if last.is_some() {
+                        builder.close();
+                    }
+                    let (x, y) = point(start);
+                    builder.move_to(x, y);
+                }
+                match curve {
+                    OutlineCurve::Line(_, p1) => {
+                        let (x, y) = point(*p1);
+                        builder.line_to(x, y);
+                    }
+                    OutlineCurve::Quad(_, p1, p2) => {
+                        let (x1, y1) = point(*p1);
+                        let (x, y) = point(*p2);
+                        builder.quad_to(x1, y1, x, y);
+                    }
+                    OutlineCurve::Cubic(_, p1, p2, p3) => {
+                        let (x1, y1) = point(*p1);
+                        let (x2, y2) = point(*p2);
+                        let (x, y) = point(*p3);
+                        builder.cubic_to(x1, y1, x2, y2, x, y);
+                    }
+                }
+                last = Some(end);
+            }
+            if last.is_some() {
+                builder.close();
+            }
+        }
+        caret += scaled.h_advance(glyph_id);
+        previous = Some(glyph_id);
+    }
+    builder.finish()
+}
+
+pub(crate) fn draw_text(
+    pixmap: &mut Pixmap,
+    font: &FontArc,
+    text: &str,
+    x: f32,
+    y: f32,
+    style: &TextStyle,
+) {
+    //! Draw `text` with its baseline at `y`, aligned around `x` according to `style.align`.
+    let start = match style.align {
+        TextAlign::Left => x,
+        TextAlign::Center => x - text_width(font, text, style.size) / 2.0,
+        TextAlign::Right => x - text_width(font, text, style.size),
+    };
+    let Some(path) = text_path(font, text, style.size, start, y) else {
+        return;
+    };
+    if let Some((halo, width)) = style.halo {
+        let stroke = Stroke {
+            width,
+            ..Stroke::default()
+        };
+        pixmap.stroke_path(
+            &path,
+            &solid_paint(halo),
+            &stroke,
+            Transform::identity(),
+            None,
+        );
+    }
+    pixmap.fill_path(
+        &path,
+        &solid_paint(style.color),
+        FillRule::Winding,
+        Transform::identity(),
+        None,
+    );
+}
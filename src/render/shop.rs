@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+
+use tiny_skia::{
+    Color, GradientStop, LinearGradient, Pixmap, Point, Rect, SpreadMode, Stroke, Transform,
+};
+
+use crate::render::{
+    color_from_hex, decode_png, draw_image, draw_text, encode_png, fit_text, new_canvas,
+    solid_paint, text_width, FontArc, RenderError, TextAlign, TextStyle,
+};
+use crate::response_types::cosmetics::CosmeticV2;
+use crate::response_types::shop::{ShopFeaturedEntry, ShopV2};
+
+#[derive(Debug, Clone)]
+pub struct ShopRenderOptions {
+    /// Font used for the title, section names, item names and prices. Text is skipped without a font.
+    pub font: Option<FontArc>,
+    /// Title drawn above the shop, followed by the shop date.
+    pub title: String,
+    pub columns: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub padding: u32,
+    pub background: Color,
+    pub text_color: Color,
+    pub discount_color: Color,
+    pub title_size: f32,
+    pub section_size: f32,
+    pub name_size: f32,
+    pub price_size: f32,
+}
+
+impl Default for ShopRenderOptions {
+    fn default() -> Self {
+        ShopRenderOptions {
+            font: None,
+            title: "Item Shop".to_string(),
+            columns: 6,
+            tile_width: 256,
+            tile_height: 320,
+            padding: 16,
+            background: Color::from_rgba8(16, 24, 48, 255),
+            text_color: Color::from_rgba8(255, 255, 255, 255),
+            discount_color: Color::from_rgba8(170, 170, 170, 255),
+            title_size: 56.0,
+            section_size: 32.0,
+            name_size: 22.0,
+            price_size: 20.0,
+        }
+    }
+}
+
+/// A group of shop entries sharing the same layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShopSection<'a> {
+    pub name: String,
+    pub index: i64,
+    pub entries: Vec<&'a ShopFeaturedEntry>,
+}
+
+pub fn shop_sections(shop: &ShopV2) -> Vec<ShopSection<'_>> {
+    //! Group the featured entries of a shop by their layout.
+    //!
+    //! Sections are ordered by the layout index and entries by descending sort priority,
+    //! which matches the order of the in-game shop. Entries without a layout name are
+    //! grouped by their section ID.
+    let mut sections: Vec<(String, ShopSection)> = Vec::new();
+    for entry in &shop.featured.entries {
+        let key = match entry.layout.id.is_empty() {
+            true => entry.section_id.clone(),
+            false => entry.layout.id.clone(),
+        };
+        match sections.iter_mut().find(|(id, _)| *id == key) {
+            Some((_, section)) => section.entries.push(entry),
+            None => {
+                let name = match entry.layout.name.is_empty() {
+                    true => entry.section_id.clone(),
+                    false => entry.layout.name.clone(),
+                };
+                let section = ShopSection {
+                    name,
+                    index: entry.layout.index,
+                    entries: vec![entry],
+                };
+                sections.push((key, section));
+            }
+        }
+    }
+
+    let mut sections = sections
+        .into_iter()
+        .map(|(_, section)| section)
+        .collect::<Vec<_>>();
+    sections.sort_by_key(|section| section.index);
+    for section in &mut sections {
+        section
+            .entries
+            .sort_by_key(|entry| std::cmp::Reverse(entry.sort_priority));
+    }
+    sections
+}
+
+pub fn render_shop(
+    shop: &ShopV2,
+    images: &HashMap<String, Vec<u8>>,
+    options: &ShopRenderOptions,
+) -> Result<Vec<u8>, RenderError> {
+    //! Render a single image of the item shop.
+    //!
+    //! ## Parameters
+    //!
+    //! - `shop`: The shop to render.
+    //! - `images`: Downloaded PNGs keyed by their URL. Tiles use the offer image of the
+    //!   entry's `new_display_asset`, falling back to the featured image or icon of the first
+    //!   item. The V-Bucks icon is taken from [`ShopV2::vbuck_icon`]. Missing or undecodable
+    //!   images are left out. `assets::ImageAssets`, behind the `assets` feature, lists all these
+    //!   URLs.
+    //! - `options`: Layout and styling of the rendered image.
+    //!
+    //! ## Returns
+    //!
+    //! The shop image as PNG bytes.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use std::collections::HashMap;
+    //!
+    //! use fortnite_api::render::shop::{render_shop, ShopRenderOptions};
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let http_client = reqwest::Client::new();
+    //!
+    //!     let shop = fortnite_api::get_shop_br_v2(&http_client, None).await.unwrap();
+    //!     let result = render_shop(&shop, &HashMap::new(), &ShopRenderOptions::default());
+    //!     assert!(result.is_ok());
+    //! }
+    //! ```
+    let sections = shop_sections(shop);
+    let padding = options.padding;
+    let columns = options.columns.max(1);
+    let (width, height) =
+        canvas_size(&sections, options).ok_or_else(|| RenderError::GridTooLarge {
+            columns,
+            rows: sections
+                .iter()
+                .map(|section| section.entries.len().div_ceil(columns as usize))
+                .sum(),
+        })?;
+    let header_height = options.title_size as u32 + 2 * padding;
+    let section_header_height = options.section_size as u32 + padding;
+    let mut pixmap = new_canvas(width, height)?;
+    pixmap.fill(options.background);
+
+    let vbuck_icon = images
+        .get(&shop.vbuck_icon)
+        .and_then(|data| decode_png(data).ok());
+    let text = |size: f32, color: Color, align: TextAlign| TextStyle {
+        size,
+        color,
+        halo: None,
+        align,
+    };
+
+    if let Some(font) = &options.font {
+        let title = format!("{} – {}", options.title, shop.date.format("%Y-%m-%d"));
+        let style = text(options.title_size, options.text_color, TextAlign::Center);
+        let baseline = (padding as f32) + options.title_size;
+        draw_text(
+            &mut pixmap,
+            font,
+            &title,
+            width as f32 / 2.0,
+            baseline,
+            &style,
+        );
+    }
+
+    let mut y = header_height;
+    for section in &sections {
+        if let Some(font) = &options.font {
+            let style = text(options.section_size, options.text_color, TextAlign::Left);
+            let baseline = y as f32 + options.section_size;
+            let name = fit_text(font, &section.name, options.section_size, width as f32);
+            draw_text(&mut pixmap, font, &name, padding as f32, baseline, &style);
+        }
+        y += section_header_height;
+
+        for (index, entry) in section.entries.iter().enumerate() {
+            let column = index as u32 % columns;
+            let row = index as u32 / columns;
+            let x = padding + column * (options.tile_width + padding);
+            let tile_y = y + row * (options.tile_height + padding);
+            let Some(tile) = Rect::from_xywh(
+                x as f32,
+                tile_y as f32,
+                options.tile_width as f32,
+                options.tile_height as f32,
+            ) else {
+                continue;
+            };
+            draw_tile(
+                &mut pixmap,
+                entry,
+                tile,
+                images,
+                vbuck_icon.as_ref(),
+                options,
+            );
+        }
+        let rows = (section.entries.len() as u32).div_ceil(columns);
+        y += rows * (options.tile_height + padding);
+    }
+
+    encode_png(&pixmap)
+}
+
+fn canvas_size(sections: &[ShopSection<'_>], options: &ShopRenderOptions) -> Option<(u32, u32)> {
+    //! The width and height of the shop image, or `None` if they overflow `u32`.
+    let padding = options.padding;
+    let columns = options.columns.max(1);
+    let width = columns
+        .checked_mul(options.tile_width)?
+        .checked_add(columns.checked_add(1)?.checked_mul(padding)?)?;
+    let row_height = options.tile_height.checked_add(padding)?;
+    let section_header_height = (options.section_size as u32).checked_add(padding)?;
+    let mut height = (options.title_size as u32).checked_add(padding.checked_mul(3)?)?;
+    for section in sections {
+        let rows = u32::try_from(section.entries.len()).ok()?.div_ceil(columns);
+        height = height
+            .checked_add(section_header_height)?
+            .checked_add(rows.checked_mul(row_height)?)?;
+    }
+    Some((width, height))
+}
+
+fn draw_tile(
+    pixmap: &mut Pixmap,
+    entry: &ShopFeaturedEntry,
+    tile: Rect,
+    images: &HashMap<String, Vec<u8>>,
+    vbuck_icon: Option<&Pixmap>,
+    options: &ShopRenderOptions,
+) {
+    let material_instance = entry
+        .new_display_asset
+        .as_ref()
+        .and_then(|display_asset| display_asset.material_instances.first());
+    let colors = material_instance.and_then(|instance| instance.colors.as_ref());
+    let rarity = entry
+        .items
+        .first()
+        .map(rarity_color)
+        .unwrap_or(Color::from_rgba8(128, 128, 128, 255));
+    let parse = |color: &Option<String>| color.as_deref().and_then(|c| color_from_hex(c).ok());
+    let top = colors
+        .and_then(|colors| parse(&colors.background_color_a))
+        .unwrap_or(rarity);
+    let bottom = colors
+        .and_then(|colors| parse(&colors.background_color_b))
+        .unwrap_or_else(|| darken(rarity));
+
+    let mut paint = solid_paint(top);
+    if let Some(shader) = LinearGradient::new(
+        Point::from_xy(tile.x(), tile.top()),
+        Point::from_xy(tile.x(), tile.bottom()),
+        vec![GradientStop::new(0.0, top), GradientStop::new(1.0, bottom)],
+        SpreadMode::Pad,
+        Transform::identity(),
+    ) {
+        paint.shader = shader;
+    }
+    pixmap.fill_rect(tile, &paint, Transform::identity(), None);
+
+    let info_height = options.name_size + options.price_size + 24.0;
+    let image_url = material_instance
+        .map(|instance| &instance.images.offer_image)
+        .or_else(|| {
+            entry
+                .items
+                .first()
+                .and_then(|item| item.images.featured.as_ref().or(item.images.icon.as_ref()))
+        });
+    let image = image_url
+        .and_then(|url| images.get(url))
+        .and_then(|data| decode_png(data).ok());
+    let image_area = Rect::from_xywh(
+        tile.x(),
+        tile.y(),
+        tile.width(),
+        tile.height() - info_height,
+    );
+    if let (Some(image), Some(image_area)) = (image, image_area) {
+        draw_image(pixmap, &image, image_area);
+    }
+
+    if let Some(info) = Rect::from_xywh(
+        tile.x(),
+        tile.bottom() - info_height,
+        tile.width(),
+        info_height,
+    ) {
+        let paint = solid_paint(Color::from_rgba8(0, 0, 0, 160));
+        pixmap.fill_rect(info, &paint, Transform::identity(), None);
+    }
+    if let Some(stripe) = Rect::from_xywh(tile.x(), tile.bottom() - 4.0, tile.width(), 4.0) {
+        pixmap.fill_rect(stripe, &solid_paint(rarity), Transform::identity(), None);
+    }
+
+    let Some(font) = &options.font else {
+        return;
+    };
+    let name = entry_name(entry);
+    let name = fit_text(font, &name, options.name_size, tile.width() - 16.0);
+    let style = TextStyle {
+        size: options.name_size,
+        color: options.text_color,
+        halo: None,
+        align: TextAlign::Center,
+    };
+    let name_baseline = tile.bottom() - info_height + 8.0 + options.name_size;
+    draw_text(
+        pixmap,
+        font,
+        &name,
+        tile.x() + tile.width() / 2.0,
+        name_baseline,
+        &style,
+    );
+
+    let price = entry.final_price.to_string();
+    let price_style = TextStyle {
+        size: options.price_size,
+        align: TextAlign::Left,
+        ..style
+    };
+    let icon_size = options.price_size;
+    let price_width = text_width(font, &price, options.price_size);
+    let regular =
+        (entry.regular_price > entry.final_price).then(|| entry.regular_price.to_string());
+    let regular_width = regular
+        .as_ref()
+        .map(|regular| text_width(font, regular, options.price_size) + 8.0)
+        .unwrap_or(0.0);
+    let total_width = icon_size + 4.0 + price_width + regular_width;
+    let mut caret = tile.x() + (tile.width() - total_width) / 2.0;
+    let price_baseline = name_baseline + 8.0 + options.price_size;
+
+    if let Some(icon) = vbuck_icon {
+        if let Some(target) = Rect::from_xywh(
+            caret,
+            price_baseline - icon_size * 0.85,
+            icon_size,
+            icon_size,
+        ) {
+            draw_image(pixmap, icon, target);
+        }
+    }
+    caret += icon_size + 4.0;
+    draw_text(pixmap, font, &price, caret, price_baseline, &price_style);
+    caret += price_width + 8.0;
+
+    if let Some(regular) = regular {
+        let regular_style = TextStyle {
+            color: options.discount_color,
+            ..price_style
+        };
+        draw_text(
+            pixmap,
+            font,
+            &regular,
+            caret,
+            price_baseline,
+            &regular_style,
+        );
+        let strike_y = price_baseline - options.price_size * 0.3;
+        let mut builder = tiny_skia::PathBuilder::new();
+        builder.move_to(caret, strike_y);
+        builder.line_to(caret + regular_width - 8.0, strike_y);
+        if let Some(path) = builder.finish() {
+            let stroke = Stroke {
+                width: 2.0,
+                ..Stroke::default()
+            };
+            let paint = solid_paint(options.discount_color);
+            pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+        }
+    }
+}
+
+fn entry_name(entry: &ShopFeaturedEntry) -> String {
+    if let Some(bundle) = &entry.bundle {
+        return bundle.name.clone();
+    }
+    match entry.items.first() {
+        Some(item) => item.name.clone(),
+        None => entry.dev_name.clone(),
+    }
+}
+
+fn rarity_color(cosmetic: &CosmeticV2) -> Color {
+    if let Some(color) = cosmetic
+        .series
+        .as_ref()
+        .and_then(|series| series.colors.first())
+        .and_then(|color| color_from_hex(color).ok())
+    {
+        return color;
+    }
+    color_from_hex(cosmetic.typed_rarity().color())
+        .unwrap_or(Color::from_rgba8(0x6a, 0x6a, 0x6a, 255))
+}
+
+fn darken(color: Color) -> Color {
+    let mut darkened = color;
+    darkened.set_red(color.red() * 0.5);
+    darkened.set_green(color.green() * 0.5);
+    darkened.set_blue(color.blue() * 0.5);
+    darkened
+}
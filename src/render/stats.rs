@@ -0,0 +1,360 @@
+use tiny_skia::{Color, Pixmap, Rect, Transform};
+
+use crate::render::{
+    decode_png, draw_image, draw_text, encode_png, fit_text, new_canvas, solid_paint, FontArc,
+    RenderError, TextAlign, TextStyle,
+};
+use crate::response_types::stats::{StatsStatsInput, StatsStatsInputMode, StatsV2};
+
+/// Colors, font and branding of a stats card.
+#[derive(Debug, Clone)]
+pub struct StatsCardTheme {
+    /// Font used for all text. Required, rendering fails with [`RenderError::MissingFont`]
+    /// without it.
+    pub font: Option<FontArc>,
+    /// PNG drawn scaled over the whole card before anything else.
+    pub background_image: Option<Vec<u8>>,
+    pub background: Color,
+    pub panel: Color,
+    pub accent: Color,
+    pub text: Color,
+    pub muted_text: Color,
+}
+
+impl Default for StatsCardTheme {
+    fn default() -> Self {
+        StatsCardTheme::dark()
+    }
+}
+
+impl StatsCardTheme {
+    pub fn dark() -> Self {
+        StatsCardTheme {
+            font: None,
+            background_image: None,
+            background: Color::from_rgba8(18, 20, 32, 255),
+            panel: Color::from_rgba8(34, 38, 58, 255),
+            accent: Color::from_rgba8(255, 196, 0, 255),
+            text: Color::from_rgba8(255, 255, 255, 255),
+            muted_text: Color::from_rgba8(150, 156, 180, 255),
+        }
+    }
+
+    pub fn light() -> Self {
+        StatsCardTheme {
+            font: None,
+            background_image: None,
+            background: Color::from_rgba8(240, 242, 248, 255),
+            panel: Color::from_rgba8(255, 255, 255, 255),
+            accent: Color::from_rgba8(40, 110, 230, 255),
+            text: Color::from_rgba8(20, 22, 30, 255),
+            muted_text: Color::from_rgba8(110, 116, 135, 255),
+        }
+    }
+
+    pub fn with_font(mut self, font: FontArc) -> Self {
+        self.font = Some(font);
+        self
+    }
+}
+
+/// The input type whose stats are shown on a card.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum StatsCardInput {
+    #[default]
+    All,
+    KeyboardMouse,
+    Gamepad,
+    Touch,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StatsCardOptions {
+    pub theme: StatsCardTheme,
+    pub input: StatsCardInput,
+}
+
+const WIDTH: u32 = 1200;
+const PADDING: f32 = 32.0;
+const HEADER_HEIGHT: f32 = 150.0;
+const TILE_HEIGHT: f32 = 130.0;
+const ROW_HEIGHT: f32 = 56.0;
+
+pub fn render_stats_card(
+    stats: &StatsV2,
+    options: &StatsCardOptions,
+) -> Result<Vec<u8>, RenderError> {
+    //! Render a stats card.
+    //!
+    //! The card shows the account name, battle pass level and progress, the overall wins,
+    //! win rate, K/D, kills and matches, and a breakdown per mode for `solo`, `duo`, `trio`,
+    //! `squad` and `ltm`. Modes without stats are left out.
+    //!
+    //! ## Parameters
+    //!
+    //! - `stats`: The player stats.
+    //! - `options`: The theme of the card and the input type to show. Falls back to the stats
+    //!   of all inputs if the chosen input type has no stats.
+    //!
+    //! ## Returns
+    //!
+    //! The stats card as PNG bytes, or [`RenderError::MissingFont`] if the theme has no font.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::render::stats::{render_stats_card, StatsCardOptions, StatsCardTheme};
+    //! use fortnite_api::render::FontArc;
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     dotenv::dotenv().ok();
+    //!     let http_client = reqwest::Client::new();
+    //!     let api_key = std::env::var("FORTNITE_API_KEY")
+    //!         .expect("Please set the FORTNITE_API_KEY environment variable");
+    //!
+    //!     let stats = fortnite_api::get_stats_v2(&http_client, api_key, "Test", None, None, None)
+    //!         .await
+    //!         .unwrap();
+    //!     let font = FontArc::try_from_vec(std::fs::read("font.ttf").unwrap()).unwrap();
+    //!     let options = StatsCardOptions {
+    //!         theme: StatsCardTheme::dark().with_font(font),
+    //!         ..StatsCardOptions::default()
+    //!     };
+    //!     let result = render_stats_card(&stats, &options);
+    //!     assert!(result.is_ok());
+    //! }
+    //! ```
+    let theme = &options.theme;
+    let font = theme.font.as_ref().ok_or(RenderError::MissingFont)?;
+    let input = select_input(stats, options.input);
+    let modes = input
+        .map(|input| {
+            [
+                ("Solo", &input.solo),
+                ("Duo", &input.duo),
+                ("Trio", &input.trio),
+                ("Squad", &input.squad),
+                ("LTM", &input.ltm),
+            ]
+            .into_iter()
+            .filter_map(|(name, mode)| mode.as_ref().map(|mode| (name, mode)))
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let table_height = match modes.is_empty() {
+        true => 0.0,
+        false => ROW_HEIGHT * (modes.len() + 1) as f32 + PADDING,
+    };
+    let height = HEADER_HEIGHT + TILE_HEIGHT + table_height + 2.0 * PADDING;
+    let mut pixmap = new_canvas(WIDTH, height as u32)?;
+    pixmap.fill(theme.background);
+    if let Some(background) = theme
+        .background_image
+        .as_ref()
+        .and_then(|data| decode_png(data).ok())
+    {
+        if let Some(target) = Rect::from_xywh(0.0, 0.0, WIDTH as f32, height) {
+            draw_image(&mut pixmap, &background, target);
+        }
+    }
+
+    let width = WIDTH as f32;
+    let inner_width = width - 2.0 * PADDING;
+    fill_rect(
+        &mut pixmap,
+        PADDING,
+        PADDING,
+        inner_width,
+        HEADER_HEIGHT - PADDING,
+        theme.panel,
+    );
+    let progress = (stats.battle_pass.progress.min(100) as f32) / 100.0;
+    let bar_y = HEADER_HEIGHT - 12.0;
+    fill_rect(
+        &mut pixmap,
+        PADDING,
+        bar_y,
+        inner_width,
+        12.0,
+        theme.muted_text,
+    );
+    fill_rect(
+        &mut pixmap,
+        PADDING,
+        bar_y,
+        inner_width * progress,
+        12.0,
+        theme.accent,
+    );
+
+    let tiles_y = HEADER_HEIGHT + PADDING;
+    let tile_count = 5.0;
+    let tile_width = (inner_width - (tile_count - 1.0) * PADDING / 2.0) / tile_count;
+    for index in 0..5 {
+        let x = PADDING + index as f32 * (tile_width + PADDING / 2.0);
+        fill_rect(
+            &mut pixmap,
+            x,
+            tiles_y,
+            tile_width,
+            TILE_HEIGHT,
+            theme.panel,
+        );
+    }
+    let table_y = tiles_y + TILE_HEIGHT + PADDING;
+    if !modes.is_empty() {
+        fill_rect(
+            &mut pixmap,
+            PADDING,
+            table_y,
+            inner_width,
+            table_height - PADDING,
+            theme.panel,
+        );
+    }
+
+    let style = |size: f32, color: Color, align: TextAlign| TextStyle {
+        size,
+        color,
+        halo: None,
+        align,
+    };
+
+    let name = fit_text(font, &stats.account.name, 56.0, inner_width * 0.65);
+    let title = style(56.0, theme.text, TextAlign::Left);
+    draw_text(
+        &mut pixmap,
+        font,
+        &name,
+        2.0 * PADDING,
+        PADDING + 72.0,
+        &title,
+    );
+    let level = format!("Level {}", stats.battle_pass.level);
+    let level_style = style(40.0, theme.accent, TextAlign::Right);
+    let right = width - 2.0 * PADDING;
+    draw_text(
+        &mut pixmap,
+        font,
+        &level,
+        right,
+        PADDING + 64.0,
+        &level_style,
+    );
+    let progress = format!("{}% to next level", stats.battle_pass.progress);
+    let progress_style = style(22.0, theme.muted_text, TextAlign::Right);
+    draw_text(
+        &mut pixmap,
+        font,
+        &progress,
+        right,
+        PADDING + 96.0,
+        &progress_style,
+    );
+
+    if let Some(overall) = input.map(|input| &input.overall) {
+        let tiles = [
+            ("Wins", overall.wins.to_string()),
+            ("Win Rate", format!("{:.1}%", overall.win_rate)),
+            ("K/D", format!("{:.2}", overall.kd)),
+            ("Kills", overall.kills.to_string()),
+            ("Matches", overall.matches.to_string()),
+        ];
+        let value_style = style(44.0, theme.text, TextAlign::Center);
+        let label_style = style(22.0, theme.muted_text, TextAlign::Center);
+        for (index, (label, value)) in tiles.iter().enumerate() {
+            let center = PADDING + index as f32 * (tile_width + PADDING / 2.0) + tile_width / 2.0;
+            draw_text(
+                &mut pixmap,
+                font,
+                value,
+                center,
+                tiles_y + 68.0,
+                &value_style,
+            );
+            draw_text(
+                &mut pixmap,
+                font,
+                label,
+                center,
+                tiles_y + 106.0,
+                &label_style,
+            );
+        }
+    } else {
+        let empty = style(32.0, theme.muted_text, TextAlign::Center);
+        let message = "No stats available";
+        let y = tiles_y + TILE_HEIGHT / 2.0 + 12.0;
+        draw_text(&mut pixmap, font, message, width / 2.0, y, &empty);
+    }
+
+    let columns = ["Matches", "Wins", "Win Rate", "Kills", "K/D"];
+    let column_x = |index: usize| PADDING + inner_width * (0.3 + 0.14 * index as f32) + 80.0;
+    let header_style = style(22.0, theme.muted_text, TextAlign::Right);
+    let mode_style = style(28.0, theme.text, TextAlign::Left);
+    let cell_style = style(28.0, theme.text, TextAlign::Right);
+    if !modes.is_empty() {
+        let baseline = table_y + ROW_HEIGHT * 0.65;
+        for (index, column) in columns.iter().enumerate() {
+            draw_text(
+                &mut pixmap,
+                font,
+                column,
+                column_x(index),
+                baseline,
+                &header_style,
+            );
+        }
+    }
+    for (row, (name, mode)) in modes.iter().enumerate() {
+        let baseline = table_y + ROW_HEIGHT * (row as f32 + 1.65);
+        draw_text(
+            &mut pixmap,
+            font,
+            name,
+            2.0 * PADDING,
+            baseline,
+            &mode_style,
+        );
+        for (index, cell) in mode_cells(mode).iter().enumerate() {
+            draw_text(
+                &mut pixmap,
+                font,
+                cell,
+                column_x(index),
+                baseline,
+                &cell_style,
+            );
+        }
+    }
+
+    encode_png(&pixmap)
+}
+
+fn select_input(stats: &StatsV2, input: StatsCardInput) -> Option<&StatsStatsInput> {
+    let selected = match input {
+        StatsCardInput::All => &stats.stats.all,
+        StatsCardInput::KeyboardMouse => &stats.stats.keyboard_mouse,
+        StatsCardInput::Gamepad => &stats.stats.gamepad,
+        StatsCardInput::Touch => &stats.stats.touch,
+    };
+    selected.as_ref().or(stats.stats.all.as_ref())
+}
+
+fn mode_cells(mode: &StatsStatsInputMode) -> [String; 5] {
+    [
+        mode.matches.to_string(),
+        mode.wins.to_string(),
+        format!("{:.1}%", mode.win_rate),
+        mode.kills.to_string(),
+        format!("{:.2}", mode.kd),
+    ]
+}
+
+fn fill_rect(pixmap: &mut Pixmap, x: f32, y: f32, width: f32, height: f32, color: Color) {
+    if let Some(rect) = Rect::from_xywh(x, y, width, height) {
+        pixmap.fill_rect(rect, &solid_paint(color), Transform::identity(), None);
+    }
+}
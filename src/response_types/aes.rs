@@ -1,13 +1,16 @@
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
+use base64::Engine;
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct AesV2 {
     pub build: String,
-    pub main_key: String,
+    pub main_key: AesKey,
     pub dynamic_keys: Vec<DynamicKey>,
     pub updated: DateTime<Utc>,
 }
@@ -16,8 +19,8 @@ pub struct AesV2 {
 #[serde(rename_all = "camelCase")]
 pub struct DynamicKey {
     pub pak_filename: String,
-    pub pak_guid: String,
-    pub key: String,
+    pub pak_guid: PakGuid,
+    pub key: AesKey,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
@@ -34,3 +37,242 @@ impl Display for AesKeyFormat {
         }
     }
 }
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AesKeyError {
+    /// The key is neither hex nor base64.
+    InvalidKey(String),
+    /// The key doesn't have 256 bits.
+    InvalidLength { key: String, bytes: usize },
+    /// The GUID is not 32 hex digits.
+    InvalidGuid(String),
+}
+
+impl Display for AesKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AesKeyError::InvalidKey(key) => write!(f, "invalid AES key: {key}"),
+            AesKeyError::InvalidLength { key, bytes } => {
+                write!(f, "AES key {key} has {bytes} bytes instead of 32")
+            }
+            AesKeyError::InvalidGuid(guid) => write!(f, "invalid pak GUID: {guid}"),
+        }
+    }
+}
+
+impl std::error::Error for AesKeyError {}
+
+/// A 256-bit AES key, parsed from hex (with or without `0x`) or base64.
+///
+/// The key remembers the format it was parsed in and is displayed and serialized the same
+/// way. Keys compare equal when their bytes are equal, regardless of format.
+///
+/// ## Example
+///
+/// ```rust
+/// use fortnite_api::response_types::aes::{AesKey, AesKeyFormat};
+///
+/// let hex = "0x".to_string() + &"AB".repeat(32);
+/// let key: AesKey = hex.parse().unwrap();
+/// let base64 = key.to_format(AesKeyFormat::Base64);
+/// assert_eq!(base64, "q6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6s=");
+/// assert_eq!(base64.parse::<AesKey>().unwrap(), key);
+/// assert_eq!(key.as_bytes(), &[0xAB; 32]);
+/// assert!("0xABCD".parse::<AesKey>().is_err());
+/// ```
+#[derive(Clone, Copy, Eq)]
+pub struct AesKey {
+    bytes: [u8; 32],
+    format: AesKeyFormat,
+}
+
+impl AesKey {
+    pub const fn from_bytes(bytes: [u8; 32]) -> Self {
+        //! A key from its raw bytes, displayed as hex.
+        AesKey {
+            bytes,
+            format: AesKeyFormat::Hex,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        //! The raw bytes of the key.
+        &self.bytes
+    }
+
+    pub fn format(&self) -> AesKeyFormat {
+        //! The format the key was parsed in.
+        self.format
+    }
+
+    pub fn with_format(mut self, format: AesKeyFormat) -> Self {
+        //! The same key, displayed and serialized in `format`.
+        self.format = format;
+        self
+    }
+
+    pub fn to_hex(&self) -> String {
+        //! The key as `0x` prefixed uppercase hex, like the API returns it.
+        format!("0x{}", hex_upper(&self.bytes))
+    }
+
+    pub fn to_base64(&self) -> String {
+        //! The key as padded standard base64.
+        base64::engine::general_purpose::STANDARD.encode(self.bytes)
+    }
+
+    pub fn to_format(&self, format: AesKeyFormat) -> String {
+        //! The key as hex or base64, regardless of its own format.
+        match format {
+            AesKeyFormat::Hex => self.to_hex(),
+            AesKeyFormat::Base64 => self.to_base64(),
+        }
+    }
+}
+
+impl PartialEq for AesKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Hash for AesKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
+}
+
+impl std::fmt::Debug for AesKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AesKey({self})")
+    }
+}
+
+impl Display for AesKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_format(self.format))
+    }
+}
+
+impl FromStr for AesKey {
+    type Err = AesKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key = s.trim();
+        let hex = key
+            .strip_prefix("0x")
+            .or_else(|| key.strip_prefix("0X"))
+            .unwrap_or(key);
+        let (bytes, format) = match decode_hex(hex) {
+            Some(bytes) => (bytes, AesKeyFormat::Hex),
+            None => (
+                base64::engine::general_purpose::STANDARD
+                    .decode(key)
+                    .map_err(|_| AesKeyError::InvalidKey(key.to_string()))?,
+                AesKeyFormat::Base64,
+            ),
+        };
+        let length = bytes.len();
+        let bytes = bytes.try_into().map_err(|_| AesKeyError::InvalidLength {
+            key: key.to_string(),
+            bytes: length,
+        })?;
+        Ok(AesKey { bytes, format })
+    }
+}
+
+impl Serialize for AesKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AesKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// The GUID of an encrypted pak, displayed as 32 uppercase hex digits like the API does.
+///
+/// Parsing also accepts lowercase digits and the `{8-4-4-4-12}` notation.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Default)]
+pub struct PakGuid([u8; 16]);
+
+impl PakGuid {
+    /// The all-zero GUID, which tools use for the main key.
+    pub const ZERO: PakGuid = PakGuid([0; 16]);
+
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        //! A GUID from its raw bytes.
+        PakGuid(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        //! The raw bytes of the GUID.
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for PakGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PakGuid({self})")
+    }
+}
+
+impl Display for PakGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", hex_upper(&self.0))
+    }
+}
+
+impl FromStr for PakGuid {
+    type Err = AesKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .trim()
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .replace('-', "");
+        decode_hex(&digits)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(PakGuid)
+            .ok_or_else(|| AesKeyError::InvalidGuid(s.to_string()))
+    }
+}
+
+impl Serialize for PakGuid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PakGuid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.is_empty() || !hex.len().is_multiple_of(2) || !hex.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        return None;
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect()
+}
+
+fn hex_upper(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -130,3 +132,294 @@ pub struct CosmeticVariantOption {
     pub name: Option<String>,
     pub image: String,
 }
+
+/// The rarity of a cosmetic, with series taking the place of the rarity they replace.
+///
+/// Variants are ordered from common to the series, so rarities can be compared and sorted.
+///
+/// ## Example
+///
+/// ```rust
+/// use fortnite_api::response_types::cosmetics::CosmeticRarity;
+///
+/// let rarity = CosmeticRarity::from_backend_value("EFortRarity::Epic");
+/// assert_eq!(rarity, CosmeticRarity::Epic);
+/// assert!(rarity > CosmeticRarity::Rare);
+/// assert_eq!(rarity.color(), "7d37be");
+/// assert_eq!(CosmeticRarity::from_backend_value("MarvelSeries").to_string(), "Marvel Series");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CosmeticRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+    Mythic,
+    Exotic,
+    Transcendent,
+    Icon,
+    Marvel,
+    Dc,
+    StarWars,
+    GamingLegends,
+    Dark,
+    Shadow,
+    Slurp,
+    Frozen,
+    Lava,
+    /// A rarity or series this version of the crate doesn't know, with its backend value.
+    Unknown(String),
+}
+
+impl CosmeticRarity {
+    pub fn from_backend_value(backend_value: &str) -> Self {
+        //! Convert a rarity backend value like `EFortRarity::Epic` or a series backend value
+        //! like `MarvelSeries`.
+        match backend_value {
+            "EFortRarity::Common" => CosmeticRarity::Common,
+            "EFortRarity::Uncommon" => CosmeticRarity::Uncommon,
+            "EFortRarity::Rare" => CosmeticRarity::Rare,
+            "EFortRarity::Epic" => CosmeticRarity::Epic,
+            "EFortRarity::Legendary" => CosmeticRarity::Legendary,
+            "EFortRarity::Mythic" => CosmeticRarity::Mythic,
+            "EFortRarity::Exotic" => CosmeticRarity::Exotic,
+            "EFortRarity::Transcendent" => CosmeticRarity::Transcendent,
+            "CreatorCollabSeries" => CosmeticRarity::Icon,
+            "MarvelSeries" => CosmeticRarity::Marvel,
+            "DCUSeries" => CosmeticRarity::Dc,
+            "ColumbusSeries" => CosmeticRarity::StarWars,
+            "PlatformSeries" => CosmeticRarity::GamingLegends,
+            "CUBESeries" => CosmeticRarity::Dark,
+            "ShadowSeries" => CosmeticRarity::Shadow,
+            "SlurpSeries" => CosmeticRarity::Slurp,
+            "FrozenSeries" => CosmeticRarity::Frozen,
+            "LavaSeries" => CosmeticRarity::Lava,
+            other => CosmeticRarity::Unknown(other.to_string()),
+        }
+    }
+
+    pub fn backend_value(&self) -> &str {
+        match self {
+            CosmeticRarity::Common => "EFortRarity::Common",
+            CosmeticRarity::Uncommon => "EFortRarity::Uncommon",
+            CosmeticRarity::Rare => "EFortRarity::Rare",
+            CosmeticRarity::Epic => "EFortRarity::Epic",
+            CosmeticRarity::Legendary => "EFortRarity::Legendary",
+            CosmeticRarity::Mythic => "EFortRarity::Mythic",
+            CosmeticRarity::Exotic => "EFortRarity::Exotic",
+            CosmeticRarity::Transcendent => "EFortRarity::Transcendent",
+            CosmeticRarity::Icon => "CreatorCollabSeries",
+            CosmeticRarity::Marvel => "MarvelSeries",
+            CosmeticRarity::Dc => "DCUSeries",
+            CosmeticRarity::StarWars => "ColumbusSeries",
+            CosmeticRarity::GamingLegends => "PlatformSeries",
+            CosmeticRarity::Dark => "CUBESeries",
+            CosmeticRarity::Shadow => "ShadowSeries",
+            CosmeticRarity::Slurp => "SlurpSeries",
+            CosmeticRarity::Frozen => "FrozenSeries",
+            CosmeticRarity::Lava => "LavaSeries",
+            CosmeticRarity::Unknown(backend_value) => backend_value,
+        }
+    }
+
+    pub fn is_series(&self) -> bool {
+        *self >= CosmeticRarity::Icon && !matches!(self, CosmeticRarity::Unknown(_))
+    }
+
+    pub fn color(&self) -> &'static str {
+        //! The canonical display color as `RRGGBB` hex.
+        match self {
+            CosmeticRarity::Common | CosmeticRarity::Unknown(_) => "6a6a6a",
+            CosmeticRarity::Uncommon => "319236",
+            CosmeticRarity::Rare => "316bc0",
+            CosmeticRarity::Epic => "7d37be",
+            CosmeticRarity::Legendary => "c56a2a",
+            CosmeticRarity::Mythic => "ba9c36",
+            CosmeticRarity::Exotic => "76d6e3",
+            CosmeticRarity::Transcendent => "d51944",
+            CosmeticRarity::Icon => "3fb3b5",
+            CosmeticRarity::Marvel => "c53334",
+            CosmeticRarity::Dc => "5475c7",
+            CosmeticRarity::StarWars => "e7c413",
+            CosmeticRarity::GamingLegends => "5b2ca8",
+            CosmeticRarity::Dark => "d313e6",
+            CosmeticRarity::Shadow => "4a4a4a",
+            CosmeticRarity::Slurp => "03c4eb",
+            CosmeticRarity::Frozen => "94dfff",
+            CosmeticRarity::Lava => "d2505c",
+        }
+    }
+}
+
+impl Display for CosmeticRarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CosmeticRarity::Common => "Common",
+            CosmeticRarity::Uncommon => "Uncommon",
+            CosmeticRarity::Rare => "Rare",
+            CosmeticRarity::Epic => "Epic",
+            CosmeticRarity::Legendary => "Legendary",
+            CosmeticRarity::Mythic => "Mythic",
+            CosmeticRarity::Exotic => "Exotic",
+            CosmeticRarity::Transcendent => "Transcendent",
+            CosmeticRarity::Icon => "Icon Series",
+            CosmeticRarity::Marvel => "Marvel Series",
+            CosmeticRarity::Dc => "DC Series",
+            CosmeticRarity::StarWars => "Star Wars Series",
+            CosmeticRarity::GamingLegends => "Gaming Legends Series",
+            CosmeticRarity::Dark => "Dark Series",
+            CosmeticRarity::Shadow => "Shadow Series",
+            CosmeticRarity::Slurp => "Slurp Series",
+            CosmeticRarity::Frozen => "Frozen Series",
+            CosmeticRarity::Lava => "Lava Series",
+            CosmeticRarity::Unknown(backend_value) => backend_value,
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The kind of a cosmetic.
+///
+/// Kinds with several backend types, such as the parts of a car, keep the backend value so
+/// [`CosmeticType::backend_value`] returns it unchanged.
+///
+/// ## Example
+///
+/// ```rust
+/// use fortnite_api::response_types::cosmetics::CosmeticType;
+///
+/// let wheels = CosmeticType::from_backend_value("VehicleCosmetics_Wheel");
+/// assert_eq!(wheels.to_string(), "Car");
+/// assert_eq!(wheels.backend_value(), "VehicleCosmetics_Wheel");
+/// assert_eq!(CosmeticType::Outfit.backend_value(), "AthenaCharacter");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CosmeticType {
+    Outfit,
+    Backpack,
+    /// A pet, with its backend value `AthenaPetCarrier` or `AthenaPet`.
+    Pet(String),
+    Pickaxe,
+    Glider,
+    Contrail,
+    Emote,
+    Emoticon,
+    Spray,
+    Toy,
+    Wrap,
+    LoadingScreen,
+    Music,
+    Banner,
+    Shoes,
+    /// A Festival instrument or aura, with its backend value like `SparksGuitar`.
+    Instrument(String),
+    LegoBuild,
+    LegoDecorBundle,
+    /// A car part, with its backend value like `VehicleCosmetics_Body`.
+    Car(String),
+    /// A type this version of the crate doesn't know, with its backend value.
+    Unknown(String),
+}
+
+impl CosmeticType {
+    pub fn from_backend_value(backend_value: &str) -> Self {
+        //! Convert a type backend value like `AthenaCharacter`.
+        match backend_value {
+            "AthenaCharacter" => CosmeticType::Outfit,
+            "AthenaBackpack" => CosmeticType::Backpack,
+            "AthenaPetCarrier" | "AthenaPet" => CosmeticType::Pet(backend_value.to_string()),
+            "AthenaPickaxe" => CosmeticType::Pickaxe,
+            "AthenaGlider" => CosmeticType::Glider,
+            "AthenaSkyDiveContrail" => CosmeticType::Contrail,
+            "AthenaDance" => CosmeticType::Emote,
+            "AthenaEmoji" => CosmeticType::Emoticon,
+            "AthenaSpray" => CosmeticType::Spray,
+            "AthenaToy" => CosmeticType::Toy,
+            "AthenaItemWrap" => CosmeticType::Wrap,
+            "AthenaLoadingScreen" => CosmeticType::LoadingScreen,
+            "AthenaMusicPack" => CosmeticType::Music,
+            "BannerToken" => CosmeticType::Banner,
+            "AthenaShoes" => CosmeticType::Shoes,
+            "SparksGuitar" | "SparksBass" | "SparksDrums" | "SparksKeyboard"
+            | "SparksMicrophone" | "SparksAura" => {
+                CosmeticType::Instrument(backend_value.to_string())
+            }
+            "JunoBuildingSet" => CosmeticType::LegoBuild,
+            "JunoBuildingProp" => CosmeticType::LegoDecorBundle,
+            "VehicleCosmetics_Body"
+            | "VehicleCosmetics_Skin"
+            | "VehicleCosmetics_Wheel"
+            | "VehicleCosmetics_DriftTrail"
+            | "VehicleCosmetics_Booster" => CosmeticType::Car(backend_value.to_string()),
+            other => CosmeticType::Unknown(other.to_string()),
+        }
+    }
+
+    pub fn backend_value(&self) -> &str {
+        match self {
+            CosmeticType::Outfit => "AthenaCharacter",
+            CosmeticType::Backpack => "AthenaBackpack",
+            CosmeticType::Pickaxe => "AthenaPickaxe",
+            CosmeticType::Glider => "AthenaGlider",
+            CosmeticType::Contrail => "AthenaSkyDiveContrail",
+            CosmeticType::Emote => "AthenaDance",
+            CosmeticType::Emoticon => "AthenaEmoji",
+            CosmeticType::Spray => "AthenaSpray",
+            CosmeticType::Toy => "AthenaToy",
+            CosmeticType::Wrap => "AthenaItemWrap",
+            CosmeticType::LoadingScreen => "AthenaLoadingScreen",
+            CosmeticType::Music => "AthenaMusicPack",
+            CosmeticType::Banner => "BannerToken",
+            CosmeticType::Shoes => "AthenaShoes",
+            CosmeticType::LegoBuild => "JunoBuildingSet",
+            CosmeticType::LegoDecorBundle => "JunoBuildingProp",
+            CosmeticType::Pet(backend_value)
+            | CosmeticType::Instrument(backend_value)
+            | CosmeticType::Car(backend_value)
+            | CosmeticType::Unknown(backend_value) => backend_value,
+        }
+    }
+}
+
+impl Display for CosmeticType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            CosmeticType::Outfit => "Outfit",
+            CosmeticType::Backpack => "Back Bling",
+            CosmeticType::Pet(_) => "Pet",
+            CosmeticType::Pickaxe => "Harvesting Tool",
+            CosmeticType::Glider => "Glider",
+            CosmeticType::Contrail => "Contrail",
+            CosmeticType::Emote => "Emote",
+            CosmeticType::Emoticon => "Emoticon",
+            CosmeticType::Spray => "Spray",
+            CosmeticType::Toy => "Toy",
+            CosmeticType::Wrap => "Wrap",
+            CosmeticType::LoadingScreen => "Loading Screen",
+            CosmeticType::Music => "Music",
+            CosmeticType::Banner => "Banner",
+            CosmeticType::Shoes => "Kicks",
+            CosmeticType::Instrument(_) => "Instrument",
+            CosmeticType::LegoBuild => "LEGO Build",
+            CosmeticType::LegoDecorBundle => "LEGO Decor Bundle",
+            CosmeticType::Car(_) => "Car",
+            CosmeticType::Unknown(backend_value) => backend_value,
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl CosmeticV2 {
+    pub fn typed_rarity(&self) -> CosmeticRarity {
+        //! The rarity of the cosmetic, or its series if it belongs to one.
+        match &self.series {
+            Some(series) => CosmeticRarity::from_backend_value(&series.backend_value),
+            None => CosmeticRarity::from_backend_value(&self.rarity.backend_value),
+        }
+    }
+
+    pub fn typed_type(&self) -> CosmeticType {
+        CosmeticType::from_backend_value(&self.r#type.backend_value)
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::Display;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -16,6 +17,8 @@ impl Display for StatsAccountType {
     }
 }
 
+/// The periods the stats endpoints accept in `timeWindow`. The API has no windows for
+/// earlier seasons.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum StatsTimeWindow {
@@ -48,7 +51,8 @@ impl Display for StatsImage {
 pub struct StatsV2 {
     pub account: StatsAccount,
     pub battle_pass: StatsBattlePass,
-    pub image: Option<String>,
+    /// The rendered stats image, if one was requested.
+    pub image: Option<Url>,
     pub stats: StatsStats,
 }
 
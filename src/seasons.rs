@@ -0,0 +1,424 @@
+//! Battle Royale seasons and the dates they ran.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::response_types::cosmetics::{CosmeticIntroduction, CosmeticV2};
+
+/// Season, absolute index, label and start date of a season.
+type BundledSeason = (Season, u8, &'static str, (i32, u32, u32));
+
+/// Every season up to Chapter 6, Season 4, which started in August 2025. The absolute index
+/// matches `CosmeticIntroduction::backend_value` and the major game version of the season.
+const BUNDLED_SEASONS: [BundledSeason; 37] = [
+    (Season::new(1, 1), 1, "1", (2017, 10, 25)),
+    (Season::new(1, 2), 2, "2", (2017, 12, 14)),
+    (Season::new(1, 3), 3, "3", (2018, 2, 22)),
+    (Season::new(1, 4), 4, "4", (2018, 5, 1)),
+    (Season::new(1, 5), 5, "5", (2018, 7, 12)),
+    (Season::new(1, 6), 6, "6", (2018, 9, 27)),
+    (Season::new(1, 7), 7, "7", (2018, 12, 6)),
+    (Season::new(1, 8), 8, "8", (2019, 2, 28)),
+    (Season::new(1, 9), 9, "9", (2019, 5, 9)),
+    (Season::new(1, 10), 10, "X", (2019, 8, 1)),
+    (Season::new(2, 1), 11, "1", (2019, 10, 15)),
+    (Season::new(2, 2), 12, "2", (2020, 2, 20)),
+    (Season::new(2, 3), 13, "3", (2020, 6, 17)),
+    (Season::new(2, 4), 14, "4", (2020, 8, 27)),
+    (Season::new(2, 5), 15, "5", (2020, 12, 2)),
+    (Season::new(2, 6), 16, "6", (2021, 3, 16)),
+    (Season::new(2, 7), 17, "7", (2021, 6, 8)),
+    (Season::new(2, 8), 18, "8", (2021, 9, 13)),
+    (Season::new(3, 1), 19, "1", (2021, 12, 5)),
+    (Season::new(3, 2), 20, "2", (2022, 3, 20)),
+    (Season::new(3, 3), 21, "3", (2022, 6, 5)),
+    (Season::new(3, 4), 22, "4", (2022, 9, 18)),
+    (Season::new(4, 1), 23, "1", (2022, 12, 4)),
+    (Season::new(4, 2), 24, "2", (2023, 3, 10)),
+    (Season::new(4, 3), 25, "3", (2023, 6, 9)),
+    (Season::new(4, 4), 26, "4", (2023, 8, 25)),
+    (Season::Special(SpecialSeason::Og), 27, "OG", (2023, 11, 3)),
+    (Season::new(5, 1), 28, "1", (2023, 12, 3)),
+    (Season::new(5, 2), 29, "2", (2024, 3, 8)),
+    (Season::new(5, 3), 30, "3", (2024, 5, 24)),
+    (Season::new(5, 4), 31, "4", (2024, 8, 16)),
+    (
+        Season::Special(SpecialSeason::Remix),
+        32,
+        "Remix",
+        (2024, 11, 2),
+    ),
+    (Season::new(6, 1), 33, "1", (2024, 12, 1)),
+    (Season::new(6, 2), 34, "2", (2025, 2, 21)),
+    (
+        Season::Special(SpecialSeason::GalacticBattle),
+        35,
+        "Galactic Battle",
+        (2025, 5, 2),
+    ),
+    (Season::new(6, 3), 36, "3", (2025, 6, 7)),
+    (Season::new(6, 4), 37, "4", (2025, 8, 7)),
+];
+
+/// A season outside the numbering of its chapter.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum SpecialSeason {
+    /// Chapter 4, Season OG.
+    Og,
+    /// Chapter 2 Remix, between Chapter 5 and Chapter 6.
+    Remix,
+    /// The Galactic Battle mini-season between Chapter 6, Season 2 and Season 3.
+    GalacticBattle,
+}
+
+impl SpecialSeason {
+    pub const ALL: [SpecialSeason; 3] = [
+        SpecialSeason::Og,
+        SpecialSeason::Remix,
+        SpecialSeason::GalacticBattle,
+    ];
+
+    pub fn chapter(&self) -> u8 {
+        //! The chapter the season is shown as part of in game.
+        match self {
+            SpecialSeason::Og => 4,
+            SpecialSeason::Remix => 2,
+            SpecialSeason::GalacticBattle => 6,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SpecialSeason::Og => "OG",
+            SpecialSeason::Remix => "Remix",
+            SpecialSeason::GalacticBattle => "Galactic Battle",
+        }
+    }
+}
+
+/// A season of a chapter, ordered chronologically.
+///
+/// Numbered seasons keep the number shown in game, so `C6S3` is Chapter 6, Season 3.
+/// Seasons outside the numbering, such as Chapter 4, Season OG, are [`SpecialSeason`]s.
+///
+/// Seasons are ordered by their absolute index in the bundled season table. Seasons missing
+/// from the table come after all bundled ones, by chapter and number.
+///
+/// ## Example
+///
+/// ```rust
+/// use fortnite_api::seasons::{Season, SpecialSeason};
+///
+/// let season: Season = "Introduced in Chapter 1, Season X.".parse().unwrap();
+/// assert_eq!(season, Season::new(1, 10));
+/// assert_eq!(season.absolute_index(), Some(10));
+/// assert_eq!("C2S1".parse::<Season>().unwrap(), Season::from_absolute_index(11).unwrap());
+/// assert!(Season::new(1, 10) < Season::new(2, 1));
+/// assert!(Season::new(5, 4) < Season::Special(SpecialSeason::Remix));
+/// assert!(Season::Special(SpecialSeason::Remix) < Season::new(6, 1));
+/// assert_eq!(Season::Special(SpecialSeason::Og).to_string(), "Chapter 4, Season OG");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(untagged)]
+pub enum Season {
+    Numbered { chapter: u8, season: u8 },
+    Special(SpecialSeason),
+}
+
+impl Season {
+    pub const fn new(chapter: u8, season: u8) -> Self {
+        //! A numbered season, e.g. `Season::new(1, 10)` for Chapter 1, Season X.
+        Season::Numbered { chapter, season }
+    }
+
+    pub fn chapter(&self) -> u8 {
+        match self {
+            Season::Numbered { chapter, .. } => *chapter,
+            Season::Special(special) => special.chapter(),
+        }
+    }
+
+    pub fn number(&self) -> Option<u8> {
+        //! The number of the season within its chapter. `None` for special seasons.
+        match self {
+            Season::Numbered { season, .. } => Some(*season),
+            Season::Special(_) => None,
+        }
+    }
+
+    pub fn absolute_index(&self) -> Option<u8> {
+        //! The season's number counted across all chapters, from the bundled season table.
+        bundled_table().get(*self).map(|info| info.index)
+    }
+
+    pub fn from_absolute_index(index: u8) -> Option<Self> {
+        //! Look up a season by its absolute index in the bundled season table.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::seasons::{Season, SpecialSeason};
+        //!
+        //! let remix = Season::from_absolute_index(32).unwrap();
+        //! assert_eq!(remix, Season::Special(SpecialSeason::Remix));
+        //! assert_eq!(remix.to_string(), "Chapter 2, Season Remix");
+        //! let galactic_battle = Season::from_absolute_index(35).unwrap();
+        //! assert_eq!(galactic_battle.to_string(), "Chapter 6, Season Galactic Battle");
+        //! assert_eq!(galactic_battle, "Chapter 6, Season Galactic Battle".parse().unwrap());
+        //! let season = Season::from_absolute_index(36).unwrap();
+        //! assert_eq!(season, "C6S3".parse().unwrap());
+        //! assert_eq!(season.to_string(), "Chapter 6, Season 3");
+        //! ```
+        bundled_table().by_index(index).map(|info| info.season)
+    }
+
+    pub fn from_introduction(introduction: &CosmeticIntroduction) -> Option<Self> {
+        //! The season a cosmetic was introduced in, preferring the backend value over the
+        //! chapter and season text.
+        introduction
+            .backend_value
+            .and_then(Season::from_absolute_index)
+            .or_else(|| {
+                let chapter = introduction.chapter.trim().parse().ok()?;
+                parse_season_label(chapter, &introduction.season)
+            })
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Season::Numbered { season, .. } => bundled_table()
+                .get(*self)
+                .map_or_else(|| season.to_string(), |info| info.label.clone()),
+            Season::Special(special) => special.label().to_string(),
+        }
+    }
+
+    /// Bundled seasons by absolute index, then the others by chapter and number.
+    fn order_key(&self) -> (bool, u8, u8) {
+        match self.absolute_index() {
+            Some(index) => (false, index, 0),
+            None => (true, self.chapter(), self.number().unwrap_or_default()),
+        }
+    }
+}
+
+impl Ord for Season {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.order_key().cmp(&other.order_key())
+    }
+}
+
+impl PartialOrd for Season {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Chapter {}, Season {}", self.chapter(), self.label())
+    }
+}
+
+impl FromStr for Season {
+    type Err = String;
+
+    /// Parse `Chapter 2, Season 3`, `Introduced in Chapter 1, Season X.` or `C2S3`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s
+            .to_lowercase()
+            .replace(|c: char| !c.is_alphanumeric(), " ");
+        let words = normalized.split_whitespace().collect::<Vec<_>>();
+        let after = |keyword: &str| {
+            words
+                .iter()
+                .position(|word| *word == keyword)
+                .and_then(|position| words.get(position + 1))
+                .copied()
+        };
+        // Labels such as `Galactic Battle` span all words after `season`.
+        let label = words
+            .iter()
+            .position(|word| *word == "season")
+            .and_then(|position| words.get(position + 1..))
+            .map(|rest| rest.join(" "));
+        let parsed = match (after("chapter"), after("season")) {
+            (Some(chapter), Some(season)) => Some((chapter, season)),
+            _ => words.iter().find_map(|word| {
+                word.strip_prefix('c')
+                    .and_then(|rest| rest.split_once('s'))
+                    .filter(|(chapter, season)| {
+                        !chapter.is_empty()
+                            && chapter.chars().all(|c| c.is_ascii_digit())
+                            && !season.is_empty()
+                    })
+            }),
+        };
+        parsed
+            .and_then(|(chapter, season)| {
+                let chapter = chapter.parse().ok()?;
+                parse_season_label(chapter, season)
+                    .or_else(|| parse_season_label(chapter, label.as_deref()?))
+            })
+            .ok_or_else(|| format!("invalid season: {s}"))
+    }
+}
+
+fn parse_season_label(chapter: u8, label: &str) -> Option<Season> {
+    //! Look up `label` among the bundled seasons of `chapter`, falling back to a season
+    //! number.
+    let label = label.trim();
+    bundled_table()
+        .seasons()
+        .iter()
+        .find(|info| info.season.chapter() == chapter && info.label.eq_ignore_ascii_case(label))
+        .map(|info| info.season)
+        .or_else(|| {
+            label
+                .parse()
+                .ok()
+                .map(|season| Season::new(chapter, season))
+        })
+}
+
+/// A season with its absolute index, display label and start date.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonInfo {
+    pub season: Season,
+    pub index: u8,
+    /// The season as shown in game, e.g. `X` or `OG`.
+    pub label: String,
+    pub start: DateTime<Utc>,
+}
+
+/// Start dates of seasons, used to map dates to the season they fall in.
+///
+/// A season lasts until the next season in the table starts; the last season never ends.
+/// The table bundled with the crate can be extended with [`SeasonTable::insert`] or
+/// replaced by deserializing an up-to-date one.
+///
+/// ## Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use fortnite_api::seasons::{Season, SeasonTable};
+///
+/// let table = SeasonTable::bundled();
+/// let added = Utc.with_ymd_and_hms(2019, 11, 1, 0, 0, 0).unwrap();
+/// assert_eq!(table.season_at(added).unwrap().season, Season::new(2, 1));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Hash, Default)]
+#[serde(transparent)]
+pub struct SeasonTable {
+    seasons: Vec<SeasonInfo>,
+}
+
+impl SeasonTable {
+    pub fn new(mut seasons: Vec<SeasonInfo>) -> Self {
+        seasons.sort_by_key(|info| info.start);
+        SeasonTable { seasons }
+    }
+
+    pub fn bundled() -> Self {
+        //! The season table shipped with this version of the crate.
+        bundled_table().clone()
+    }
+
+    pub fn insert(&mut self, info: SeasonInfo) {
+        //! Add a season, replacing an existing entry for the same season.
+        self.seasons
+            .retain(|existing| existing.season != info.season);
+        let position = self
+            .seasons
+            .partition_point(|existing| existing.start <= info.start);
+        self.seasons.insert(position, info);
+    }
+
+    pub fn seasons(&self) -> &[SeasonInfo] {
+        //! All seasons, oldest first.
+        &self.seasons
+    }
+
+    pub fn get(&self, season: Season) -> Option<&SeasonInfo> {
+        self.seasons.iter().find(|info| info.season == season)
+    }
+
+    pub fn by_index(&self, index: u8) -> Option<&SeasonInfo> {
+        self.seasons.iter().find(|info| info.index == index)
+    }
+
+    pub fn season_at(&self, date: DateTime<Utc>) -> Option<&SeasonInfo> {
+        //! The season running at `date`, e.g. `CosmeticV2::added` or `PlaylistV1::added`.
+        //! Returns `None` for dates before the first season.
+        let position = self.seasons.partition_point(|info| info.start <= date);
+        position
+            .checked_sub(1)
+            .and_then(|position| self.seasons.get(position))
+    }
+
+    pub fn end_of(&self, season: Season) -> Option<DateTime<Utc>> {
+        //! When `season` ended, i.e. the start of the following season.
+        let position = self.seasons.iter().position(|info| info.season == season)?;
+        self.seasons.get(position + 1).map(|next| next.start)
+    }
+}
+
+fn bundled_table() -> &'static SeasonTable {
+    static TABLE: OnceLock<SeasonTable> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        SeasonTable::new(
+            BUNDLED_SEASONS
+                .iter()
+                .filter_map(|(season, index, label, (year, month, day))| {
+                    Some(SeasonInfo {
+                        season: *season,
+                        index: *index,
+                        label: label.to_string(),
+                        start: NaiveDate::from_ymd_opt(*year, *month, *day)?
+                            .and_hms_opt(0, 0, 0)?
+                            .and_utc(),
+                    })
+                })
+                .collect(),
+        )
+    })
+}
+
+pub fn cosmetics_by_season<'a>(
+    cosmetics: impl IntoIterator<Item = &'a CosmeticV2>,
+) -> BTreeMap<Season, Vec<&'a CosmeticV2>> {
+    //! Group cosmetics by the season they were introduced in. Cosmetics without a known
+    //! introduction are left out.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! # #[tokio::main]
+    //! # async fn main() {
+    //! let http_client = reqwest::Client::new();
+    //! let cosmetics = fortnite_api::get_cosmetics_v2(&http_client, None).await.unwrap();
+    //!
+    //! for (season, cosmetics) in fortnite_api::seasons::cosmetics_by_season(&cosmetics) {
+    //!     println!("{season}: {} cosmetics", cosmetics.len());
+    //! }
+    //! # }
+    //! ```
+    let mut seasons: BTreeMap<Season, Vec<&CosmeticV2>> = BTreeMap::new();
+    for cosmetic in cosmetics {
+        if let Some(season) = cosmetic
+            .introduction
+            .as_ref()
+            .and_then(Season::from_introduction)
+        {
+            seasons.entry(season).or_default().push(cosmetic);
+        }
+    }
+    seasons
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+
+use crate::response_types::shop::ShopV2;
+
+/// The time of day the item shop resets, in UTC.
+pub const SHOP_RESET_TIME: NaiveTime = NaiveTime::MIN;
+
+pub fn shop_date<Tz: TimeZone>(at: &DateTime<Tz>) -> NaiveDate {
+    //! The date of the shop that is live at `at`.
+    //!
+    //! Works with timestamps in any timezone; shop dates always follow the UTC reset.
+    //!
+    //! ## Example
+    //!
+    //! ```rust
+    //! use chrono::{FixedOffset, NaiveDate, TimeZone};
+    //! use fortnite_api::shop::shop_date;
+    //!
+    //! // 20:00 on the 18th in New York is already the 19th's shop.
+    //! let new_york = FixedOffset::west_opt(4 * 3600).unwrap();
+    //! let at = new_york.with_ymd_and_hms(2024, 10, 18, 20, 0, 0).unwrap();
+    //! assert_eq!(shop_date(&at), NaiveDate::from_ymd_opt(2024, 10, 19).unwrap());
+    //! ```
+    (at.with_timezone(&Utc) - SHOP_RESET_TIME.signed_duration_since(NaiveTime::MIN)).date_naive()
+}
+
+pub fn previous_reset<Tz: TimeZone>(now: &DateTime<Tz>) -> DateTime<Tz> {
+    //! The last shop reset at or before `now`, in the timezone of `now`.
+    shop_date(now)
+        .and_time(SHOP_RESET_TIME)
+        .and_utc()
+        .with_timezone(&now.timezone())
+}
+
+pub fn next_reset<Tz: TimeZone>(now: &DateTime<Tz>) -> DateTime<Tz> {
+    //! The first shop reset after `now`, in the timezone of `now`.
+    //!
+    //! ## Example
+    //!
+    //! ```rust
+    //! use chrono::{TimeDelta, TimeZone, Utc};
+    //! use fortnite_api::shop::{next_reset, time_until_reset};
+    //!
+    //! let now = Utc.with_ymd_and_hms(2024, 10, 18, 22, 30, 0).unwrap();
+    //! assert_eq!(next_reset(&now), Utc.with_ymd_and_hms(2024, 10, 19, 0, 0, 0).unwrap());
+    //! assert_eq!(time_until_reset(&now), TimeDelta::minutes(90));
+    //! ```
+    previous_reset(now) + TimeDelta::days(1)
+}
+
+pub fn time_until_reset<Tz: TimeZone>(now: &DateTime<Tz>) -> TimeDelta {
+    //! The time left until the next shop reset.
+    next_reset(now).signed_duration_since(now)
+}
+
+pub fn is_current_shop<Tz: TimeZone>(shop: &ShopV2, now: &DateTime<Tz>) -> bool {
+    //! Whether `shop` is the shop that is live at `now`.
+    shop_date(&shop.date) == shop_date(now)
+}
+
+pub fn is_stale<Tz: TimeZone>(shop: &ShopV2, now: &DateTime<Tz>) -> bool {
+    //! Whether a cached `shop` has been replaced by a newer one at `now`.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! # #[tokio::main]
+    //! # async fn main() {
+    //! let http_client = reqwest::Client::new();
+    //! let shop = fortnite_api::get_shop_br_v2(&http_client, None).await.unwrap();
+    //!
+    //! if fortnite_api::shop::is_stale(&shop, &chrono::Utc::now()) {
+    //!     println!("The shop has reset, fetch it again");
+    //! }
+    //! # }
+    //! ```
+    shop_date(&shop.date) < shop_date(now)
+}
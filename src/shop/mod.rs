@@ -0,0 +1,12 @@
+//! Offline helpers on top of the item shop: wishlists, offer value and the reset clock.
+
+mod clock;
+mod value;
+mod wishlist;
+
+pub use clock::{
+    is_current_shop, is_stale, next_reset, previous_reset, shop_date, time_until_reset,
+    SHOP_RESET_TIME,
+};
+pub use value::{best_value_offers, discount_percent, offer_values, standard_price, OfferValue};
+pub use wishlist::{Wishlist, WishlistMatch, WishlistReport, WishlistRule};
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use crate::response_types::cosmetics::{CosmeticRarity, CosmeticType, CosmeticV2};
+use crate::response_types::shop::{ShopFeaturedEntry, ShopV2};
+
+pub fn discount_percent(entry: &ShopFeaturedEntry) -> f64 {
+    //! The discount of an offer as a percentage of its regular price.
+    if entry.regular_price == 0 {
+        return 0.0;
+    }
+    entry.regular_price.saturating_sub(entry.final_price) as f64 / entry.regular_price as f64
+        * 100.0
+}
+
+pub fn standard_price(cosmetic: &CosmeticV2) -> Option<u64> {
+    //! The long-standing V-Bucks price of a cosmetic's type and rarity when sold on its own.
+    //!
+    //! Only outfits, pickaxes, gliders, emotes and wraps have fixed tiers. Series cosmetics
+    //! are priced individually and return `None`.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! # #[tokio::main]
+    //! # async fn main() {
+    //! let http_client = reqwest::Client::new();
+    //! let shop = fortnite_api::get_shop_br_v2(&http_client, None).await.unwrap();
+    //!
+    //! for entry in shop.featured.entries.iter().filter(|entry| entry.items.len() == 1) {
+    //!     let Some(item) = entry.items.first() else { continue };
+    //!     if let Some(standard) = fortnite_api::shop::standard_price(item) {
+    //!         println!("{}: {} (usually {standard})", item.name, entry.final_price);
+    //!     }
+    //! }
+    //! # }
+    //! ```
+    let price = match (cosmetic.typed_type(), cosmetic.typed_rarity()) {
+        (CosmeticType::Outfit, CosmeticRarity::Uncommon) => 800,
+        (CosmeticType::Outfit, CosmeticRarity::Rare) => 1200,
+        (CosmeticType::Outfit, CosmeticRarity::Epic) => 1500,
+        (CosmeticType::Outfit, CosmeticRarity::Legendary) => 2000,
+        (CosmeticType::Pickaxe | CosmeticType::Glider, CosmeticRarity::Uncommon) => 500,
+        (CosmeticType::Pickaxe | CosmeticType::Glider, CosmeticRarity::Rare) => 800,
+        (CosmeticType::Pickaxe | CosmeticType::Glider, CosmeticRarity::Epic) => 1200,
+        (CosmeticType::Pickaxe | CosmeticType::Glider, CosmeticRarity::Legendary) => 1500,
+        (CosmeticType::Emote, CosmeticRarity::Uncommon) => 200,
+        (CosmeticType::Emote, CosmeticRarity::Rare) => 500,
+        (CosmeticType::Emote, CosmeticRarity::Epic) => 800,
+        (CosmeticType::Wrap, CosmeticRarity::Uncommon) => 300,
+        (CosmeticType::Wrap, CosmeticRarity::Rare) => 500,
+        (CosmeticType::Wrap, CosmeticRarity::Epic) => 700,
+        _ => return None,
+    };
+    Some(price)
+}
+
+/// The value of an offer compared to other ways of buying its items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OfferValue<'a> {
+    pub entry: &'a ShopFeaturedEntry,
+    /// The discount off the regular price in V-Bucks.
+    pub discount: u64,
+    pub discount_percent: f64,
+    /// The cost of buying every item through its cheapest other single-item offer in the
+    /// same shop, if all of them are sold on their own elsewhere.
+    pub standalone_price: Option<u64>,
+    /// The sum of the [`standard_price`]s of the items, if all of them have one.
+    pub standard_price: Option<u64>,
+    /// The price the offer is compared against: the standalone price if known, else the
+    /// standard price, else the regular price.
+    pub reference_price: u64,
+    /// `reference_price - final_price`. Negative if the offer is more expensive.
+    pub savings: i64,
+    pub savings_percent: f64,
+}
+
+impl<'a> OfferValue<'a> {
+    fn new(
+        entry: &'a ShopFeaturedEntry,
+        standalone: &HashMap<&str, Vec<&ShopFeaturedEntry>>,
+    ) -> Self {
+        let standalone_price = (!entry.items.is_empty())
+            .then(|| {
+                entry
+                    .items
+                    .iter()
+                    .map(|item| {
+                        standalone
+                            .get(item.id.as_str())?
+                            .iter()
+                            .filter(|offer| offer.offer_id != entry.offer_id)
+                            .map(|offer| offer.final_price)
+                            .min()
+                    })
+                    .sum::<Option<u64>>()
+            })
+            .flatten();
+        let standard_price = (!entry.items.is_empty())
+            .then(|| entry.items.iter().map(standard_price).sum::<Option<u64>>())
+            .flatten();
+        let reference_price = standalone_price
+            .or(standard_price)
+            .unwrap_or(entry.regular_price);
+        let savings = reference_price as i64 - entry.final_price as i64;
+        OfferValue {
+            entry,
+            discount: entry.regular_price.saturating_sub(entry.final_price),
+            discount_percent: discount_percent(entry),
+            standalone_price,
+            standard_price,
+            reference_price,
+            savings,
+            savings_percent: if reference_price == 0 {
+                0.0
+            } else {
+                savings as f64 / reference_price as f64 * 100.0
+            },
+        }
+    }
+}
+
+pub fn offer_values(shop: &ShopV2) -> Vec<OfferValue<'_>> {
+    //! Analyze the value of every offer in a shop, in shop order.
+    let mut standalone: HashMap<&str, Vec<&ShopFeaturedEntry>> = HashMap::new();
+    for entry in &shop.featured.entries {
+        let [item] = entry.items.as_slice() else {
+            continue;
+        };
+        standalone.entry(item.id.as_str()).or_default().push(entry);
+    }
+    shop.featured
+        .entries
+        .iter()
+        .map(|entry| OfferValue::new(entry, &standalone))
+        .collect()
+}
+
+pub fn best_value_offers(shop: &ShopV2) -> Vec<OfferValue<'_>> {
+    //! Rank the offers of a shop by how much they save compared to their reference price.
+    //!
+    //! ## Parameters
+    //!
+    //! - `shop`: The shop to rank.
+    //!
+    //! ## Returns
+    //!
+    //! All offers, the highest relative savings first. Ties are broken by absolute savings.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! # #[tokio::main]
+    //! # async fn main() {
+    //! let http_client = reqwest::Client::new();
+    //! let shop = fortnite_api::get_shop_br_v2(&http_client, None).await.unwrap();
+    //!
+    //! for value in fortnite_api::shop::best_value_offers(&shop).iter().take(5) {
+    //!     println!(
+    //!         "{}: {} V-Bucks, saves {} ({:.0}%)",
+    //!         value.entry.dev_name, value.entry.final_price, value.savings, value.savings_percent
+    //!     );
+    //! }
+    //! # }
+    //! ```
+    let mut values = offer_values(shop);
+    values.sort_by(|a, b| {
+        b.savings_percent
+            .total_cmp(&a.savings_percent)
+            .then(b.savings.cmp(&a.savings))
+    });
+    values
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::response_types::cosmetics::CosmeticV2;
+use crate::response_types::shop::ShopV2;
+
+/// Cosmetics a user wants to be notified about, by ID or by name pattern.
+///
+/// IDs are compared case-insensitively. Name patterns match the whole cosmetic name,
+/// ignoring case, where `*` matches any run of characters and `?` a single character.
+///
+/// ## Example
+///
+/// ```rust no_run
+/// use fortnite_api::shop::Wishlist;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let http_client = reqwest::Client::new();
+/// let shop = fortnite_api::get_shop_br_v2(&http_client, None).await.unwrap();
+///
+/// let wishlist = Wishlist::new()
+///     .with_id("CID_029_Athena_Commando_F_Halloween")
+///     .with_pattern("*raider*");
+/// let report = wishlist.check(&shop);
+/// for found in &report.matches {
+///     println!("{} for {} V-Bucks", found.cosmetic_name, found.final_price);
+/// }
+/// println!("{}", serde_json::to_string(&report).unwrap());
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Wishlist {
+    /// Lowercase cosmetic IDs.
+    #[serde(deserialize_with = "deserialize_ids")]
+    ids: BTreeSet<String>,
+    pub patterns: Vec<String>,
+}
+
+/// Why a cosmetic matched a [`Wishlist`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase", tag = "type", content = "value")]
+pub enum WishlistRule {
+    Id(String),
+    Pattern(String),
+}
+
+/// A wishlisted cosmetic found in a shop offer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct WishlistMatch {
+    pub cosmetic_id: String,
+    pub cosmetic_name: String,
+    pub rule: WishlistRule,
+    pub offer_id: String,
+    pub section_id: String,
+    /// The bundle the cosmetic is sold in, if the offer is a bundle.
+    pub bundle_name: Option<String>,
+    /// The number of items in the offer, including the wishlisted one.
+    pub offer_items: usize,
+    pub regular_price: u64,
+    pub final_price: u64,
+    pub discounted: bool,
+}
+
+/// The result of checking a [`Wishlist`] against a shop.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct WishlistReport {
+    pub shop_hash: String,
+    pub shop_date: DateTime<Utc>,
+    /// Every offer containing a wishlisted cosmetic, in shop order. A cosmetic sold both
+    /// on its own and in a bundle appears once per offer.
+    pub matches: Vec<WishlistMatch>,
+    /// Wishlisted IDs that are not in the shop.
+    pub missing_ids: Vec<String>,
+}
+
+impl WishlistReport {
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+}
+
+impl Wishlist {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.add_id(id);
+        self
+    }
+
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.add_pattern(pattern);
+        self
+    }
+
+    pub fn ids(&self) -> &BTreeSet<String> {
+        //! The wishlisted cosmetic IDs, in lowercase.
+        &self.ids
+    }
+
+    pub fn add_id(&mut self, id: impl Into<String>) -> bool {
+        //! Add a cosmetic ID, ignoring case. Returns `false` if it was already wishlisted.
+        self.ids.insert(id.into().to_lowercase())
+    }
+
+    pub fn remove_id(&mut self, id: &str) -> bool {
+        //! Remove a cosmetic ID, ignoring case. Returns `false` if it wasn't wishlisted.
+        self.ids.remove(&id.to_lowercase())
+    }
+
+    pub fn add_pattern(&mut self, pattern: impl Into<String>) {
+        let pattern = pattern.into();
+        if !self.patterns.contains(&pattern) {
+            self.patterns.push(pattern);
+        }
+    }
+
+    pub fn remove_pattern(&mut self, pattern: &str) -> bool {
+        let before = self.patterns.len();
+        self.patterns.retain(|wishlisted| wishlisted != pattern);
+        self.patterns.len() != before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty() && self.patterns.is_empty()
+    }
+
+    pub fn matches(&self, cosmetic: &CosmeticV2) -> Option<WishlistRule> {
+        //! Check whether a cosmetic is wishlisted. IDs take precedence over patterns.
+        let id = cosmetic.id.to_lowercase();
+        if self.ids.contains(&id) {
+            return Some(WishlistRule::Id(id));
+        }
+        self.patterns
+            .iter()
+            .find(|pattern| glob_match(pattern, &cosmetic.name))
+            .map(|pattern| WishlistRule::Pattern(pattern.clone()))
+    }
+
+    pub fn check(&self, shop: &ShopV2) -> WishlistReport {
+        //! Find all wishlisted cosmetics in a shop, including items inside bundles.
+        //!
+        //! ## Parameters
+        //!
+        //! - `shop`: The shop to check.
+        //!
+        //! ## Returns
+        //!
+        //! The matching offers and the wishlisted IDs that are not available.
+        let mut matches = vec![];
+        for entry in &shop.featured.entries {
+            for item in &entry.items {
+                let Some(rule) = self.matches(item) else {
+                    continue;
+                };
+                matches.push(WishlistMatch {
+                    cosmetic_id: item.id.clone(),
+                    cosmetic_name: item.name.clone(),
+                    rule,
+                    offer_id: entry.offer_id.clone(),
+                    section_id: entry.section_id.clone(),
+                    bundle_name: entry.bundle.as_ref().map(|bundle| bundle.name.clone()),
+                    offer_items: entry.items.len(),
+                    regular_price: entry.regular_price,
+                    final_price: entry.final_price,
+                    discounted: entry.final_price < entry.regular_price,
+                });
+            }
+        }
+        let missing_ids = self
+            .ids
+            .iter()
+            .filter(|id| {
+                !matches
+                    .iter()
+                    .any(|found| found.cosmetic_id.eq_ignore_ascii_case(id))
+            })
+            .cloned()
+            .collect();
+        WishlistReport {
+            shop_hash: shop.hash.clone(),
+            shop_date: shop.date,
+            matches,
+            missing_ids,
+        }
+    }
+}
+
+fn deserialize_ids<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeSet<String>, D::Error> {
+    let ids = BTreeSet::<String>::deserialize(deserializer)?;
+    Ok(ids.into_iter().map(|id| id.to_lowercase()).collect())
+}
+
+/// Case-insensitive wildcard match of the whole `text`.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    // Greedy matching with backtracking to the last `*`.
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match (pattern.get(p), text.get(t)) {
+            (Some('*'), _) => {
+                star = Some((p, t));
+                p += 1;
+            }
+            (Some(expected), Some(actual)) if *expected == '?' || expected == actual => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern
+        .get(p..)
+        .unwrap_or_default()
+        .iter()
+        .all(|c| *c == '*')
+}
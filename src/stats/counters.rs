@@ -0,0 +1,134 @@
+use std::ops::{Add, AddAssign};
+
+use serde::{Deserialize, Serialize};
+
+use crate::response_types::stats::{StatsStatsInputMode, StatsStatsInputOverall};
+use crate::stats::LOBBY_SIZE;
+
+/// The cumulative counters of a stats mode, with rates derived from them.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsCounters {
+    pub score: u64,
+    pub wins: u64,
+    pub kills: u64,
+    pub deaths: u64,
+    pub matches: u64,
+    pub minutes_played: u64,
+    pub players_outlived: u64,
+}
+
+impl StatsCounters {
+    pub fn kd(&self) -> f64 {
+        //! Kills per death. With no deaths, the number of kills.
+        self.kills as f64 / self.deaths.max(1) as f64
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        //! Wins per match in percent, like `StatsStatsInputMode::win_rate`.
+        ratio(self.wins, self.matches) * 100.0
+    }
+
+    pub fn kills_per_match(&self) -> f64 {
+        ratio(self.kills, self.matches)
+    }
+
+    pub fn kills_per_min(&self) -> f64 {
+        ratio(self.kills, self.minutes_played)
+    }
+
+    pub fn score_per_match(&self) -> f64 {
+        ratio(self.score, self.matches)
+    }
+
+    pub fn score_per_min(&self) -> f64 {
+        ratio(self.score, self.minutes_played)
+    }
+
+    pub fn survival_ratio(&self) -> f64 {
+        //! The share of other players in the lobby outlived per match, from 0 to 1.
+        ratio(self.players_outlived, self.matches * (LOBBY_SIZE - 1))
+    }
+
+    pub fn average_placement(&self) -> Option<f64> {
+        //! An estimate of the average placement in a full lobby, from `players_outlived`.
+        //! `None` without any matches.
+        if self.matches == 0 {
+            return None;
+        }
+        let outlived = ratio(self.players_outlived, self.matches);
+        Some((LOBBY_SIZE as f64 - outlived).max(1.0))
+    }
+
+    pub fn saturating_sub(&self, earlier: &StatsCounters) -> StatsCounters {
+        //! The counters gained since `earlier`. Counters that went down, e.g. because a
+        //! season ended, count as zero.
+        StatsCounters {
+            score: self.score.saturating_sub(earlier.score),
+            wins: self.wins.saturating_sub(earlier.wins),
+            kills: self.kills.saturating_sub(earlier.kills),
+            deaths: self.deaths.saturating_sub(earlier.deaths),
+            matches: self.matches.saturating_sub(earlier.matches),
+            minutes_played: self.minutes_played.saturating_sub(earlier.minutes_played),
+            players_outlived: self
+                .players_outlived
+                .saturating_sub(earlier.players_outlived),
+        }
+    }
+}
+
+impl Add for StatsCounters {
+    type Output = StatsCounters;
+
+    fn add(mut self, other: StatsCounters) -> StatsCounters {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for StatsCounters {
+    fn add_assign(&mut self, other: StatsCounters) {
+        self.score += other.score;
+        self.wins += other.wins;
+        self.kills += other.kills;
+        self.deaths += other.deaths;
+        self.matches += other.matches;
+        self.minutes_played += other.minutes_played;
+        self.players_outlived += other.players_outlived;
+    }
+}
+
+impl From<&StatsStatsInputOverall> for StatsCounters {
+    fn from(overall: &StatsStatsInputOverall) -> Self {
+        StatsCounters {
+            score: overall.score,
+            wins: overall.wins.into(),
+            kills: overall.kills.into(),
+            deaths: overall.deaths.into(),
+            matches: overall.matches.into(),
+            minutes_played: overall.minutes_played,
+            players_outlived: overall.players_outlived.into(),
+        }
+    }
+}
+
+impl From<&StatsStatsInputMode> for StatsCounters {
+    fn from(mode: &StatsStatsInputMode) -> Self {
+        StatsCounters {
+            score: mode.score,
+            wins: mode.wins.into(),
+            kills: mode.kills.into(),
+            deaths: mode.deaths.into(),
+            matches: mode.matches.into(),
+            minutes_played: mode.minutes_played,
+            players_outlived: mode.players_outlived.into(),
+        }
+    }
+}
+
+fn ratio(numerator: u64, denominator: u64) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+    numerator as f64 / denominator as f64
+}
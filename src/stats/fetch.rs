@@ -0,0 +1,227 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use futures_util::{stream, StreamExt};
+use reqwest::StatusCode;
+
+use crate::response_types::stats::{StatsAccountType, StatsTimeWindow, StatsV2};
+use crate::stats::{StatsQuery, StatsQueryError};
+
+/// A player to fetch stats for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatsPlayer {
+    Name {
+        name: String,
+        account_type: Option<StatsAccountType>,
+    },
+    AccountId(String),
+}
+
+impl StatsPlayer {
+    pub fn name(name: impl Into<String>) -> Self {
+        StatsPlayer::Name {
+            name: name.into(),
+            account_type: None,
+        }
+    }
+
+    pub fn account_id(account_id: impl Into<String>) -> Self {
+        StatsPlayer::AccountId(account_id.into())
+    }
+}
+
+impl Display for StatsPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatsPlayer::Name { name, .. } => write!(f, "{name}"),
+            StatsPlayer::AccountId(account_id) => write!(f, "{account_id}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StatsFetchError {
+    /// The account exists but its stats are not public.
+    Private,
+    /// No account with this name or ID exists, or it has no stats.
+    NotFound,
+    /// The API rejected the request because of too many requests; try again later.
+    RateLimited {
+        /// How long the API asked to wait, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+    InvalidQuery(StatsQueryError),
+    Http(reqwest::Error),
+}
+
+impl Display for StatsFetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatsFetchError::Private => write!(f, "the account's stats are private"),
+            StatsFetchError::NotFound => write!(f, "the account was not found"),
+            StatsFetchError::RateLimited { .. } => write!(f, "rate limited by the API"),
+            StatsFetchError::InvalidQuery(error) => write!(f, "invalid stats query: {error}"),
+            StatsFetchError::Http(error) => write!(f, "failed to fetch stats: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for StatsFetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StatsFetchError::InvalidQuery(error) => Some(error),
+            StatsFetchError::Http(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<StatsQueryError> for StatsFetchError {
+    fn from(error: StatsQueryError) -> Self {
+        StatsFetchError::InvalidQuery(error)
+    }
+}
+
+impl From<reqwest::Error> for StatsFetchError {
+    fn from(error: reqwest::Error) -> Self {
+        match error.status() {
+            Some(StatusCode::FORBIDDEN) => StatsFetchError::Private,
+            Some(StatusCode::NOT_FOUND) => StatsFetchError::NotFound,
+            Some(StatusCode::TOO_MANY_REQUESTS) => {
+                StatsFetchError::RateLimited { retry_after: None }
+            }
+            _ => StatsFetchError::Http(error),
+        }
+    }
+}
+
+/// The result of [`StatsFetcher::fetch_all`], in the order the players were given.
+#[derive(Debug, Default)]
+pub struct StatsBatch {
+    pub fetched: Vec<(StatsPlayer, StatsV2)>,
+    pub failed: Vec<(StatsPlayer, StatsFetchError)>,
+}
+
+impl StatsBatch {
+    pub fn stats(&self) -> impl Iterator<Item = &StatsV2> {
+        self.fetched.iter().map(|(_, stats)| stats)
+    }
+
+    pub fn rate_limited(&self) -> impl Iterator<Item = &StatsPlayer> {
+        //! The players which were still rate limited after all retries and should be
+        //! fetched again later.
+        self.failed
+            .iter()
+            .filter(|(_, error)| matches!(error, StatsFetchError::RateLimited { .. }))
+            .map(|(player, _)| player)
+    }
+}
+
+/// Fetches the stats of many players with bounded concurrency.
+///
+/// Rate limited requests are retried after the delay of the `Retry-After` header, or with
+/// exponential backoff starting at one second if the API sends none. Players whose stats are
+/// private or missing don't fail the batch.
+#[derive(Debug, Clone)]
+pub struct StatsFetcher {
+    http_client: reqwest::Client,
+    api_key: String,
+    concurrency: usize,
+    max_retries: u32,
+    time_window: Option<StatsTimeWindow>,
+}
+
+impl StatsFetcher {
+    pub fn new(http_client: reqwest::Client, api_key: impl Into<String>) -> Self {
+        StatsFetcher {
+            http_client,
+            api_key: api_key.into(),
+            concurrency: 3,
+            max_retries: 3,
+            time_window: None,
+        }
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        //! Set the maximum number of simultaneous requests. Defaults to 3.
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        //! Set how often a rate limited request is retried. Defaults to 3.
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_time_window(mut self, time_window: StatsTimeWindow) -> Self {
+        self.time_window = Some(time_window);
+        self
+    }
+
+    pub async fn fetch(&self, player: &StatsPlayer) -> Result<StatsV2, StatsFetchError> {
+        //! Fetch the stats of a single player, retrying if rate limited.
+        let mut query = StatsQuery::from(player);
+        if let Some(time_window) = &self.time_window {
+            query = query.with_time_window(time_window.clone());
+        }
+        let mut backoff = Duration::from_secs(1);
+        let mut retries = 0;
+        loop {
+            match query.fetch(&self.http_client, self.api_key.clone()).await {
+                Err(StatsFetchError::RateLimited { retry_after }) if retries < self.max_retries => {
+                    tokio::time::sleep(retry_after.unwrap_or(backoff)).await;
+                    backoff = backoff.saturating_mul(2);
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    pub async fn fetch_all(&self, players: impl IntoIterator<Item = StatsPlayer>) -> StatsBatch {
+        //! Fetch the stats of all players.
+        //!
+        //! ## Parameters
+        //!
+        //! - `players`: The players to fetch, by name or account ID.
+        //!
+        //! ## Returns
+        //!
+        //! The fetched stats and the players that failed, both in the given order.
+        //!
+        //! ## Example
+        //!
+        //! ```rust no_run
+        //! use fortnite_api::stats::{StatsFetcher, StatsPlayer};
+        //!
+        //! #[tokio::main]
+        //! async fn main() {
+        //!     let http_client = reqwest::Client::new();
+        //!     let fetcher = StatsFetcher::new(http_client, "your_api_key");
+        //!
+        //!     let players = ["Ninja", "Bugha", "Clix"].map(StatsPlayer::name);
+        //!     let batch = fetcher.fetch_all(players).await;
+        //!     for (player, error) in &batch.failed {
+        //!         println!("{player}: {error}");
+        //!     }
+        //! }
+        //! ```
+        let mut batch = StatsBatch::default();
+        let results = stream::iter(players)
+            .map(|player| async move {
+                let result = self.fetch(&player).await;
+                (player, result)
+            })
+            .buffered(self.concurrency)
+            .collect::<Vec<_>>()
+            .await;
+        for (player, result) in results {
+            match result {
+                Ok(stats) => batch.fetched.push((player, stats)),
+                Err(error) => batch.failed.push((player, error)),
+            }
+        }
+        batch
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::response_types::stats::{StatsStatsInput, StatsV2};
+use crate::stats::StatsCounters;
+
+/// Stats of an account at a point in time.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshot {
+    pub taken_at: DateTime<Utc>,
+    pub stats: StatsV2,
+}
+
+/// What changed in one input type between two snapshots.
+///
+/// Modes without any matches in the later snapshot are `None`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InputDelta {
+    pub overall: StatsCounters,
+    pub solo: Option<StatsCounters>,
+    pub duo: Option<StatsCounters>,
+    pub trio: Option<StatsCounters>,
+    pub squad: Option<StatsCounters>,
+    pub ltm: Option<StatsCounters>,
+}
+
+impl InputDelta {
+    fn between(before: Option<&StatsStatsInput>, after: &StatsStatsInput) -> Self {
+        let mode = |select: fn(&StatsStatsInput) -> Option<StatsCounters>| {
+            let later = select(after)?;
+            let earlier = before.and_then(select).unwrap_or_default();
+            Some(later.saturating_sub(&earlier))
+        };
+        InputDelta {
+            overall: StatsCounters::from(&after.overall).saturating_sub(
+                &before
+                    .map(|before| StatsCounters::from(&before.overall))
+                    .unwrap_or_default(),
+            ),
+            solo: mode(|input| input.solo.as_ref().map(StatsCounters::from)),
+            duo: mode(|input| input.duo.as_ref().map(StatsCounters::from)),
+            trio: mode(|input| input.trio.as_ref().map(StatsCounters::from)),
+            squad: mode(|input| input.squad.as_ref().map(StatsCounters::from)),
+            ltm: mode(|input| input.ltm.as_ref().map(StatsCounters::from)),
+        }
+    }
+}
+
+/// The change in an account's stats between two snapshots.
+///
+/// Rates like [`StatsCounters::kd`] on the deltas describe only the interval.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsDelta {
+    pub account_id: String,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub battle_pass_levels: i64,
+    pub all: Option<InputDelta>,
+    pub keyboard_mouse: Option<InputDelta>,
+    pub gamepad: Option<InputDelta>,
+    pub touch: Option<InputDelta>,
+}
+
+impl StatsDelta {
+    pub fn between(before: &StatsSnapshot, after: &StatsSnapshot) -> Self {
+        //! Compute what changed from `before` to `after`.
+        //!
+        //! ## Example
+        //!
+        //! ```rust no_run
+        //! # #[tokio::main]
+        //! # async fn main() {
+        //! use fortnite_api::stats::{StatsDelta, StatsSnapshot};
+        //!
+        //! let http_client = reqwest::Client::new();
+        //! let api_key = "your_api_key".to_string();
+        //! let fetch = || async {
+        //!     let stats = fortnite_api::get_stats_v2(&http_client, api_key.clone(), "Ninja", None, None, None)
+        //!         .await
+        //!         .unwrap();
+        //!     StatsSnapshot { taken_at: chrono::Utc::now(), stats }
+        //! };
+        //!
+        //! let before = fetch().await;
+        //! // ... play a session ...
+        //! let after = fetch().await;
+        //!
+        //! if let Some(all) = StatsDelta::between(&before, &after).all {
+        //!     let session = all.overall;
+        //!     println!("{} kills in {} matches, K/D {:.2}", session.kills, session.matches, session.kd());
+        //! }
+        //! # }
+        //! ```
+        let input = |select: fn(&StatsV2) -> Option<&StatsStatsInput>| {
+            select(&after.stats).map(|later| InputDelta::between(select(&before.stats), later))
+        };
+        StatsDelta {
+            account_id: after.stats.account.id.clone(),
+            from: before.taken_at,
+            to: after.taken_at,
+            battle_pass_levels: i64::from(after.stats.battle_pass.level)
+                - i64::from(before.stats.battle_pass.level),
+            all: input(|stats| stats.stats.all.as_ref()),
+            keyboard_mouse: input(|stats| stats.stats.keyboard_mouse.as_ref()),
+            gamepad: input(|stats| stats.stats.gamepad.as_ref()),
+            touch: input(|stats| stats.stats.touch.as_ref()),
+        }
+    }
+}
+
+/// Stats snapshots of several accounts over time.
+///
+/// The history is serializable so it can be persisted between runs.
+///
+/// ## Example
+///
+/// ```rust no_run
+/// # #[tokio::main]
+/// # async fn main() {
+/// use fortnite_api::stats::StatsHistory;
+///
+/// let http_client = reqwest::Client::new();
+/// let api_key = "your_api_key".to_string();
+/// let mut history: StatsHistory =
+///     serde_json::from_str(&std::fs::read_to_string("stats_history.json").unwrap()).unwrap();
+///
+/// let stats = fortnite_api::get_stats_v2(&http_client, api_key, "Ninja", None, None, None)
+///     .await
+///     .unwrap();
+/// let account_id = stats.account.id.clone();
+/// history.record(stats, chrono::Utc::now());
+///
+/// if let Some(all) = history.last_session(&account_id).and_then(|delta| delta.all) {
+///     println!("+{} wins, {:.1}% win rate", all.overall.wins, all.overall.win_rate());
+/// }
+/// std::fs::write("stats_history.json", serde_json::to_string(&history).unwrap()).unwrap();
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(transparent)]
+pub struct StatsHistory {
+    accounts: BTreeMap<String, Vec<StatsSnapshot>>,
+}
+
+impl StatsHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, stats: StatsV2, taken_at: DateTime<Utc>) {
+        //! Add a snapshot of `stats` taken at `taken_at`.
+        let snapshots = self.accounts.entry(stats.account.id.clone()).or_default();
+        let position = snapshots.partition_point(|snapshot| snapshot.taken_at <= taken_at);
+        snapshots.insert(position, StatsSnapshot { taken_at, stats });
+    }
+
+    pub fn accounts(&self) -> impl Iterator<Item = &str> {
+        //! The IDs of all accounts with snapshots.
+        self.accounts.keys().map(String::as_str)
+    }
+
+    pub fn snapshots(&self, account_id: &str) -> &[StatsSnapshot] {
+        //! All snapshots of an account, oldest first.
+        self.accounts.get(account_id).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn latest(&self, account_id: &str) -> Option<&StatsSnapshot> {
+        self.snapshots(account_id).last()
+    }
+
+    pub fn snapshot_at(&self, account_id: &str, at: DateTime<Utc>) -> Option<&StatsSnapshot> {
+        //! The last snapshot taken at or before `at`.
+        let snapshots = self.snapshots(account_id);
+        let position = snapshots.partition_point(|snapshot| snapshot.taken_at <= at);
+        position
+            .checked_sub(1)
+            .and_then(|position| snapshots.get(position))
+    }
+
+    pub fn delta(
+        &self,
+        account_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Option<StatsDelta> {
+        //! The change between the snapshots in effect at `from` and at `to`. If `from` is
+        //! before the first snapshot, the first snapshot is used.
+        let before = self
+            .snapshot_at(account_id, from)
+            .or_else(|| self.snapshots(account_id).first())?;
+        let after = self.snapshot_at(account_id, to)?;
+        Some(StatsDelta::between(before, after))
+    }
+
+    pub fn last_session(&self, account_id: &str) -> Option<StatsDelta> {
+        //! The change between the two latest snapshots of an account.
+        match self.snapshots(account_id) {
+            [.., before, after] => Some(StatsDelta::between(before, after)),
+            _ => None,
+        }
+    }
+
+    pub fn prune_before(&mut self, before: DateTime<Utc>) {
+        //! Drop all snapshots taken before `before`.
+        for snapshots in self.accounts.values_mut() {
+            snapshots.retain(|snapshot| snapshot.taken_at >= before);
+        }
+        self.accounts.retain(|_, snapshots| !snapshots.is_empty());
+    }
+}
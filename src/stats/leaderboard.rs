@@ -0,0 +1,385 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::response_types::stats::{StatsStatsInput, StatsV2};
+use crate::stats::{StatsCounters, StatsMetrics, LOBBY_SIZE};
+
+/// The input type stats are grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsInput {
+    #[default]
+    All,
+    KeyboardMouse,
+    Gamepad,
+    Touch,
+}
+
+impl StatsInput {
+    pub const ALL: [StatsInput; 4] = [
+        StatsInput::All,
+        StatsInput::KeyboardMouse,
+        StatsInput::Gamepad,
+        StatsInput::Touch,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatsInput::All => "all",
+            StatsInput::KeyboardMouse => "keyboard_mouse",
+            StatsInput::Gamepad => "gamepad",
+            StatsInput::Touch => "touch",
+        }
+    }
+
+    pub fn select<'a>(&self, stats: &'a StatsV2) -> Option<&'a StatsStatsInput> {
+        match self {
+            StatsInput::All => stats.stats.all.as_ref(),
+            StatsInput::KeyboardMouse => stats.stats.keyboard_mouse.as_ref(),
+            StatsInput::Gamepad => stats.stats.gamepad.as_ref(),
+            StatsInput::Touch => stats.stats.touch.as_ref(),
+        }
+    }
+}
+
+impl FromStr for StatsInput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StatsInput::ALL
+            .into_iter()
+            .find(|input| input.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown input type: {s}"))
+    }
+}
+
+/// The game mode stats are grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsMode {
+    #[default]
+    Overall,
+    Solo,
+    Duo,
+    Trio,
+    Squad,
+    Ltm,
+}
+
+impl StatsMode {
+    pub const ALL: [StatsMode; 6] = [
+        StatsMode::Overall,
+        StatsMode::Solo,
+        StatsMode::Duo,
+        StatsMode::Trio,
+        StatsMode::Squad,
+        StatsMode::Ltm,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatsMode::Overall => "overall",
+            StatsMode::Solo => "solo",
+            StatsMode::Duo => "duo",
+            StatsMode::Trio => "trio",
+            StatsMode::Squad => "squad",
+            StatsMode::Ltm => "ltm",
+        }
+    }
+
+    pub fn counters(&self, input: &StatsStatsInput) -> Option<StatsCounters> {
+        match self {
+            StatsMode::Overall => Some(StatsCounters::from(&input.overall)),
+            StatsMode::Solo => input.solo.as_ref().map(StatsCounters::from),
+            StatsMode::Duo => input.duo.as_ref().map(StatsCounters::from),
+            StatsMode::Trio => input.trio.as_ref().map(StatsCounters::from),
+            StatsMode::Squad => input.squad.as_ref().map(StatsCounters::from),
+            StatsMode::Ltm => input.ltm.as_ref().map(StatsCounters::from),
+        }
+    }
+}
+
+impl FromStr for StatsMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StatsMode::ALL
+            .into_iter()
+            .find(|mode| mode.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown mode: {s}"))
+    }
+}
+
+pub fn stats_counters(
+    stats: &StatsV2,
+    input: StatsInput,
+    mode: StatsMode,
+) -> Option<StatsCounters> {
+    //! The counters of `stats` for an input type and mode, if the player has any.
+    //!
+    //! See [`StatsMetrics::new`] for how a missing `all` input type is handled.
+    StatsMetrics::new(stats, input, mode).map(|metrics| metrics.counters)
+}
+
+/// A value players can be ranked by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsMetric {
+    Score,
+    Wins,
+    Kills,
+    Deaths,
+    Matches,
+    MinutesPlayed,
+    PlayersOutlived,
+    Kd,
+    WinRate,
+    KillsPerMatch,
+    KillsPerMin,
+    ScorePerMatch,
+    ScorePerMin,
+    SurvivalRatio,
+    AveragePlacement,
+}
+
+impl StatsMetric {
+    pub const ALL: [StatsMetric; 15] = [
+        StatsMetric::Score,
+        StatsMetric::Wins,
+        StatsMetric::Kills,
+        StatsMetric::Deaths,
+        StatsMetric::Matches,
+        StatsMetric::MinutesPlayed,
+        StatsMetric::PlayersOutlived,
+        StatsMetric::Kd,
+        StatsMetric::WinRate,
+        StatsMetric::KillsPerMatch,
+        StatsMetric::KillsPerMin,
+        StatsMetric::ScorePerMatch,
+        StatsMetric::ScorePerMin,
+        StatsMetric::SurvivalRatio,
+        StatsMetric::AveragePlacement,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatsMetric::Score => "score",
+            StatsMetric::Wins => "wins",
+            StatsMetric::Kills => "kills",
+            StatsMetric::Deaths => "deaths",
+            StatsMetric::Matches => "matches",
+            StatsMetric::MinutesPlayed => "minutes_played",
+            StatsMetric::PlayersOutlived => "players_outlived",
+            StatsMetric::Kd => "kd",
+            StatsMetric::WinRate => "win_rate",
+            StatsMetric::KillsPerMatch => "kills_per_match",
+            StatsMetric::KillsPerMin => "kills_per_min",
+            StatsMetric::ScorePerMatch => "score_per_match",
+            StatsMetric::ScorePerMin => "score_per_min",
+            StatsMetric::SurvivalRatio => "survival_ratio",
+            StatsMetric::AveragePlacement => "average_placement",
+        }
+    }
+
+    pub fn value(&self, counters: &StatsCounters) -> f64 {
+        match self {
+            StatsMetric::Score => counters.score as f64,
+            StatsMetric::Wins => counters.wins as f64,
+            StatsMetric::Kills => counters.kills as f64,
+            StatsMetric::Deaths => counters.deaths as f64,
+            StatsMetric::Matches => counters.matches as f64,
+            StatsMetric::MinutesPlayed => counters.minutes_played as f64,
+            StatsMetric::PlayersOutlived => counters.players_outlived as f64,
+            StatsMetric::Kd => counters.kd(),
+            StatsMetric::WinRate => counters.win_rate(),
+            StatsMetric::KillsPerMatch => counters.kills_per_match(),
+            StatsMetric::KillsPerMin => counters.kills_per_min(),
+            StatsMetric::ScorePerMatch => counters.score_per_match(),
+            StatsMetric::ScorePerMin => counters.score_per_min(),
+            StatsMetric::SurvivalRatio => counters.survival_ratio(),
+            StatsMetric::AveragePlacement => {
+                counters.average_placement().unwrap_or(LOBBY_SIZE as f64)
+            }
+        }
+    }
+
+    pub fn higher_is_better(&self) -> bool {
+        //! Whether a higher value ranks better, which is true for all but deaths and the
+        //! average placement.
+        !matches!(self, StatsMetric::Deaths | StatsMetric::AveragePlacement)
+    }
+
+    fn compare(&self, a: f64, b: f64) -> Ordering {
+        //! Order values best first.
+        if self.higher_is_better() {
+            b.total_cmp(&a)
+        } else {
+            a.total_cmp(&b)
+        }
+    }
+}
+
+impl FromStr for StatsMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StatsMetric::ALL
+            .into_iter()
+            .find(|metric| metric.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown metric: {s}"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry<'a> {
+    /// 1-based rank. Players with equal values share a rank.
+    pub rank: usize,
+    pub stats: &'a StatsV2,
+    pub counters: StatsCounters,
+    pub value: f64,
+}
+
+pub fn leaderboard<'a>(
+    players: impl IntoIterator<Item = &'a StatsV2>,
+    input: StatsInput,
+    mode: StatsMode,
+    metric: StatsMetric,
+) -> Vec<LeaderboardEntry<'a>> {
+    //! Rank players by a metric for an input type and mode.
+    //!
+    //! Players without stats for the input type and mode are left out, ties keep the given order.
+    //!
+    //! ## Example
+    //!
+    //! ```rust no_run
+    //! use fortnite_api::stats::{leaderboard, StatsFetcher, StatsInput, StatsMetric, StatsMode, StatsPlayer};
+    //!
+    //! #[tokio::main]
+    //! async fn main() {
+    //!     let fetcher = StatsFetcher::new(reqwest::Client::new(), "your_api_key");
+    //!     let batch = fetcher.fetch_all(["Ninja", "Bugha"].map(StatsPlayer::name)).await;
+    //!
+    //!     for entry in leaderboard(batch.stats(), StatsInput::All, StatsMode::Solo, StatsMetric::Kd) {
+    //!         println!("#{} {} {:.2}", entry.rank, entry.stats.account.name, entry.value);
+    //!     }
+    //! }
+    //! ```
+    let mut entries = players
+        .into_iter()
+        .filter_map(|stats| {
+            let counters = stats_counters(stats, input, mode)?;
+            Some(LeaderboardEntry {
+                rank: 0,
+                stats,
+                counters,
+                value: metric.value(&counters),
+            })
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| metric.compare(a.value, b.value));
+
+    let mut previous: Option<(f64, usize)> = None;
+    for (position, entry) in entries.iter_mut().enumerate() {
+        entry.rank = match previous {
+            Some((value, rank)) if value == entry.value => rank,
+            _ => position + 1,
+        };
+        previous = Some((entry.value, entry.rank));
+    }
+    entries
+}
+
+/// Which of two compared players is ahead.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum Side {
+    Left,
+    Right,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricComparison {
+    pub metric: StatsMetric,
+    pub left: f64,
+    pub right: f64,
+    /// `None` if both values are equal.
+    pub leader: Option<Side>,
+}
+
+/// A metric-by-metric comparison of two players.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadToHead<'a> {
+    pub left: &'a StatsV2,
+    pub right: &'a StatsV2,
+    pub input: StatsInput,
+    pub mode: StatsMode,
+    pub comparisons: Vec<MetricComparison>,
+}
+
+impl<'a> HeadToHead<'a> {
+    pub fn new(
+        left: &'a StatsV2,
+        right: &'a StatsV2,
+        input: StatsInput,
+        mode: StatsMode,
+    ) -> Option<Self> {
+        //! Compare two players on every [`StatsMetric`].
+        //!
+        //! ## Returns
+        //!
+        //! `None` if either player has no stats for the input type and mode.
+        let left_counters = stats_counters(left, input, mode)?;
+        let right_counters = stats_counters(right, input, mode)?;
+        let comparisons = StatsMetric::ALL
+            .into_iter()
+            .map(|metric| {
+                let left = metric.value(&left_counters);
+                let right = metric.value(&right_counters);
+                let leader = match metric.compare(left, right) {
+                    Ordering::Less => Some(Side::Left),
+                    Ordering::Greater => Some(Side::Right),
+                    Ordering::Equal => None,
+                };
+                MetricComparison {
+                    metric,
+                    left,
+                    right,
+                    leader,
+                }
+            })
+            .collect();
+        Some(HeadToHead {
+            left,
+            right,
+            input,
+            mode,
+            comparisons,
+        })
+    }
+
+    pub fn get(&self, metric: StatsMetric) -> Option<&MetricComparison> {
+        self.comparisons
+            .iter()
+            .find(|comparison| comparison.metric == metric)
+    }
+
+    pub fn wins(&self, side: Side) -> usize {
+        //! The number of metrics `side` leads in.
+        self.comparisons
+            .iter()
+            .filter(|comparison| comparison.leader == Some(side))
+            .count()
+    }
+
+    pub fn leader(&self) -> Option<Side> {
+        //! The player leading in more metrics, `None` on a tie.
+        match self.wins(Side::Left).cmp(&self.wins(Side::Right)) {
+            Ordering::Greater => Some(Side::Left),
+            Ordering::Less => Some(Side::Right),
+            Ordering::Equal => None,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::{Add, AddAssign};
+
+use serde::{Deserialize, Serialize};
+
+use crate::response_types::stats::{
+    StatsStatsInput, StatsStatsInputMode, StatsStatsInputOverall, StatsV2,
+};
+use crate::stats::{StatsCounters, StatsInput, StatsMode};
+
+/// The number of players in a Battle Royale lobby, used to estimate placements.
+pub const LOBBY_SIZE: u64 = 100;
+
+/// Placement counters normalized to the share of the lobby they cover.
+///
+/// Each mode only tracks some of the `top_N` fields, counted in teams: solo tracks top 10
+/// and 25, duo top 5 and 12, trio and squad top 3 and 6. Each pair covers roughly the best
+/// tenth and the best quarter of the lobby, so they are comparable across modes.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Placements {
+    pub wins: u64,
+    /// Sum of `top_3`, `top_5` and `top_10`. `None` if the mode tracks none of them.
+    pub top_tenth: Option<u64>,
+    /// Sum of `top_6`, `top_12` and `top_25`. `None` if the mode tracks none of them.
+    pub top_quarter: Option<u64>,
+}
+
+impl Add for Placements {
+    type Output = Placements;
+
+    fn add(mut self, other: Placements) -> Placements {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for Placements {
+    fn add_assign(&mut self, other: Placements) {
+        let sum = |a: Option<u64>, b: Option<u64>| match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or_default() + b.unwrap_or_default()),
+        };
+        self.wins += other.wins;
+        self.top_tenth = sum(self.top_tenth, other.top_tenth);
+        self.top_quarter = sum(self.top_quarter, other.top_quarter);
+    }
+}
+
+impl From<&StatsStatsInputOverall> for Placements {
+    fn from(overall: &StatsStatsInputOverall) -> Self {
+        Placements {
+            wins: overall.wins.into(),
+            top_tenth: Some(
+                u64::from(overall.top_3) + u64::from(overall.top_5) + u64::from(overall.top_10),
+            ),
+            top_quarter: Some(
+                u64::from(overall.top_6) + u64::from(overall.top_12) + u64::from(overall.top_25),
+            ),
+        }
+    }
+}
+
+impl From<&StatsStatsInputMode> for Placements {
+    fn from(mode: &StatsStatsInputMode) -> Self {
+        let tier = |counts: [Option<u32>; 3]| {
+            counts
+                .into_iter()
+                .flatten()
+                .map(u64::from)
+                .reduce(|a, b| a + b)
+        };
+        Placements {
+            wins: mode.wins.into(),
+            top_tenth: tier([mode.top_3, mode.top_5, mode.top_10]),
+            top_quarter: tier([mode.top_6, mode.top_12, mode.top_25]),
+        }
+    }
+}
+
+/// A unified view of the stats of one input type and mode.
+///
+/// ## Example
+///
+/// ```rust no_run
+/// use fortnite_api::stats::{StatsInput, StatsMetrics, StatsMode};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let http_client = reqwest::Client::new();
+///     let stats = fortnite_api::get_stats_v2(&http_client, "your_api_key".to_string(), "Ninja", None, None, None)
+///         .await
+///         .unwrap();
+///
+///     for mode in [StatsMode::Solo, StatsMode::Duo, StatsMode::Squad] {
+///         if let Some(metrics) = StatsMetrics::new(&stats, StatsInput::All, mode) {
+///             println!(
+///                 "{}: top 10% in {:.1}% of matches, survived {:.0}% of the lobby",
+///                 mode.name(),
+///                 metrics.top_tenth_rate().unwrap_or_default(),
+///                 metrics.counters.survival_ratio() * 100.0,
+///             );
+///         }
+///     }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsMetrics {
+    pub counters: StatsCounters,
+    pub placements: Placements,
+}
+
+impl StatsMetrics {
+    pub fn new(stats: &StatsV2, input: StatsInput, mode: StatsMode) -> Option<Self> {
+        //! The metrics of `stats` for an input type and mode.
+        //!
+        //! ## Returns
+        //!
+        //! `None` if the player has no stats for the input type and mode. If the response
+        //! has no `all` stats, e.g. because an image platform was requested, `All` sums up
+        //! the other input types.
+        match input.select(stats) {
+            Some(stats) => Self::from_input(stats, mode),
+            None if input == StatsInput::All => StatsInput::ALL
+                .into_iter()
+                .filter(|input| *input != StatsInput::All)
+                .filter_map(|input| Self::new(stats, input, mode))
+                .reduce(|a, b| a + b),
+            None => None,
+        }
+    }
+
+    pub fn from_input(input: &StatsStatsInput, mode: StatsMode) -> Option<Self> {
+        match mode {
+            StatsMode::Overall => Some(StatsMetrics {
+                counters: StatsCounters::from(&input.overall),
+                placements: Placements::from(&input.overall),
+            }),
+            StatsMode::Solo => input.solo.as_ref().map(Self::from),
+            StatsMode::Duo => input.duo.as_ref().map(Self::from),
+            StatsMode::Trio => input.trio.as_ref().map(Self::from),
+            StatsMode::Squad => input.squad.as_ref().map(Self::from),
+            StatsMode::Ltm => input.ltm.as_ref().map(Self::from),
+        }
+    }
+
+    pub fn top_tenth_rate(&self) -> Option<f64> {
+        //! Percentage of matches placed in the best tenth of the lobby, including wins.
+        self.placements
+            .top_tenth
+            .map(|top_tenth| self.match_percentage(top_tenth))
+    }
+
+    pub fn top_quarter_rate(&self) -> Option<f64> {
+        //! Percentage of matches placed in the best quarter of the lobby, including wins.
+        self.placements
+            .top_quarter
+            .map(|top_quarter| self.match_percentage(top_quarter))
+    }
+
+    fn match_percentage(&self, count: u64) -> f64 {
+        if self.counters.matches == 0 {
+            return 0.0;
+        }
+        count as f64 / self.counters.matches as f64 * 100.0
+    }
+}
+
+impl From<&StatsStatsInputMode> for StatsMetrics {
+    fn from(mode: &StatsStatsInputMode) -> Self {
+        StatsMetrics {
+            counters: StatsCounters::from(mode),
+            placements: Placements::from(mode),
+        }
+    }
+}
+
+impl Add for StatsMetrics {
+    type Output = StatsMetrics;
+
+    fn add(mut self, other: StatsMetrics) -> StatsMetrics {
+        self.counters += other.counters;
+        self.placements += other.placements;
+        self
+    }
+}
@@ -0,0 +1,18 @@
+//! Fetching, tracking and comparing player stats.
+
+mod counters;
+mod fetch;
+mod history;
+mod leaderboard;
+mod metrics;
+mod query;
+
+pub use counters::StatsCounters;
+pub use fetch::{StatsBatch, StatsFetchError, StatsFetcher, StatsPlayer};
+pub use history::{InputDelta, StatsDelta, StatsHistory, StatsSnapshot};
+pub use leaderboard::{
+    leaderboard, stats_counters, HeadToHead, LeaderboardEntry, MetricComparison, Side, StatsInput,
+    StatsMetric, StatsMode,
+};
+pub use metrics::{Placements, StatsMetrics, LOBBY_SIZE};
+pub use query::{StatsQuery, StatsQueryError};
@@ -0,0 +1,219 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode, Url};
+
+use crate::response_types::stats::{StatsAccountType, StatsImage, StatsTimeWindow, StatsV2};
+use crate::stats::{StatsFetchError, StatsPlayer};
+use crate::utils::fetch::{send_request, APIData};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum StatsQueryError {
+    EmptyName,
+    /// Account IDs are 32 hexadecimal characters.
+    InvalidAccountId(String),
+    /// An account type was set on a lookup by account ID, which the API doesn't accept.
+    AccountTypeWithAccountId,
+}
+
+impl Display for StatsQueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatsQueryError::EmptyName => write!(f, "the player name is empty"),
+            StatsQueryError::InvalidAccountId(account_id) => {
+                write!(f, "invalid account ID: {account_id}")
+            }
+            StatsQueryError::AccountTypeWithAccountId => {
+                write!(f, "an account type can only be used when looking up a name")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StatsQueryError {}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Lookup {
+    Name(String),
+    AccountId(String),
+}
+
+/// A request for the stats of a player, covering every option of the stats endpoints.
+///
+/// ## Example
+///
+/// ```rust no_run
+/// use fortnite_api::response_types::stats::{StatsAccountType, StatsImage, StatsTimeWindow};
+/// use fortnite_api::stats::StatsQuery;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let http_client = reqwest::Client::new();
+///     let stats = StatsQuery::by_name("Ninja")
+///         .with_account_type(StatsAccountType::Epic)
+///         .with_time_window(StatsTimeWindow::Season)
+///         .with_image(StatsImage::KeyboardMouse)
+///         .fetch(&http_client, "your_api_key".to_string())
+///         .await
+///         .unwrap();
+///     if let Some(image) = stats.image {
+///         println!("Stats image: {image}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsQuery {
+    lookup: Lookup,
+    account_type: Option<StatsAccountType>,
+    time_window: Option<StatsTimeWindow>,
+    image: Option<StatsImage>,
+}
+
+impl StatsQuery {
+    pub fn by_name(name: impl Into<String>) -> Self {
+        Self::new(Lookup::Name(name.into()))
+    }
+
+    pub fn by_account_id(account_id: impl Into<String>) -> Self {
+        Self::new(Lookup::AccountId(account_id.into()))
+    }
+
+    fn new(lookup: Lookup) -> Self {
+        StatsQuery {
+            lookup,
+            account_type: None,
+            time_window: None,
+            image: None,
+        }
+    }
+
+    pub fn with_account_type(mut self, account_type: StatsAccountType) -> Self {
+        //! The platform of the account. Defaults to Epic. Only valid for lookups by name, as
+        //! account IDs are unique across platforms. Validating a lookup by account ID with an
+        //! account type fails with [`StatsQueryError::AccountTypeWithAccountId`].
+        self.account_type = Some(account_type);
+        self
+    }
+
+    pub fn with_time_window(mut self, time_window: StatsTimeWindow) -> Self {
+        //! The stats of the current season or of all time. Defaults to lifetime.
+        self.time_window = Some(time_window);
+        self
+    }
+
+    pub fn with_image(mut self, image: StatsImage) -> Self {
+        //! Let the API render a stats image for an input type, returned in `StatsV2::image`.
+        self.image = Some(image);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), StatsQueryError> {
+        //! Check the query without sending it.
+        match &self.lookup {
+            Lookup::Name(name) if name.trim().is_empty() => Err(StatsQueryError::EmptyName),
+            Lookup::AccountId(account_id)
+                if account_id.len() != 32 || !account_id.chars().all(|c| c.is_ascii_hexdigit()) =>
+            {
+                Err(StatsQueryError::InvalidAccountId(account_id.clone()))
+            }
+            Lookup::AccountId(_) if self.account_type.is_some() => {
+                Err(StatsQueryError::AccountTypeWithAccountId)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn url(&self) -> Result<Url, StatsQueryError> {
+        //! The URL of the validated query.
+        self.validate()?;
+        Ok(self.build_url())
+    }
+
+    pub(crate) fn build_url(&self) -> Url {
+        let mut url = match &self.lookup {
+            Lookup::Name(name) => {
+                let mut url = Url::from_str("https://fortnite-api.com/v2/stats/br/v2").unwrap();
+                url.query_pairs_mut().append_pair("name", name);
+                url
+            }
+            Lookup::AccountId(account_id) => Url::from_str(
+                format!("https://fortnite-api.com/v2/stats/br/v2/{account_id}").as_str(),
+            )
+            .unwrap(),
+        };
+        if let Some(account_type) = &self.account_type {
+            url.query_pairs_mut()
+                .append_pair("accountType", &account_type.to_string().to_lowercase());
+        }
+        if let Some(time_window) = &self.time_window {
+            url.query_pairs_mut()
+                .append_pair("timeWindow", &time_window.to_string().to_lowercase());
+        }
+        if let Some(image) = &self.image {
+            url.query_pairs_mut()
+                .append_pair("image", &image.to_string().to_lowercase());
+        }
+        url
+    }
+
+    pub async fn fetch(
+        &self,
+        http_client: &reqwest::Client,
+        api_key: String,
+    ) -> Result<StatsV2, StatsFetchError> {
+        //! Validate and send the query.
+        //!
+        //! ## Parameters
+        //!
+        //! - `http_client`: The reqwest client.
+        //! - `api_key`: Your Fortnite API key.
+        //!
+        //! ## Returns
+        //!
+        //! The player stats, or why they couldn't be fetched.
+        let url = self.url()?;
+        let headers = [("Authorization".to_string(), api_key)]
+            .into_iter()
+            .collect();
+        let response = send_request(http_client, url, "GET", "", &headers).await?;
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Err(StatsFetchError::RateLimited {
+                retry_after: retry_after(&response),
+            });
+        }
+        let response = response.error_for_status()?;
+        Ok(response.json::<APIData<StatsV2>>().await?.data)
+    }
+}
+
+/// The delay requested by a `Retry-After` header, given in seconds or as an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        at.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+impl From<&StatsPlayer> for StatsQuery {
+    fn from(player: &StatsPlayer) -> Self {
+        match player {
+            StatsPlayer::Name { name, account_type } => StatsQuery {
+                account_type: account_type.clone(),
+                ..StatsQuery::by_name(name.clone())
+            },
+            // The account ID endpoint has no account type parameter.
+            StatsPlayer::AccountId(account_id) => StatsQuery::by_account_id(account_id.clone()),
+        }
+    }
+}
@@ -0,0 +1,449 @@
+//! SQLite persistence of historical API snapshots. Requires the `sqlite` feature.
+//!
+//! Every stored response becomes a row in the `snapshots` table holding its kind, fetch
+//! timestamp, content hash and full JSON payload. The interesting parts of each response are
+//! additionally normalized into per-kind tables (`shop_entries`, `shop_entry_items`,
+//! `news_motds`, `news_messages`, `playlists`, `aes_keys`, `aes_dynamic_keys`,
+//! `new_cosmetics` and `stats`) which can be queried directly through
+//! [`SnapshotStore::connection`]. The `stats` table only holds the overall counters of
+//! all input types (`stats.all.overall`); per input type and mode stats are only part of the
+//! payload.
+
+use std::fmt::Display;
+use std::path::Path;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use sha2::{Digest, Sha256};
+
+use crate::response_types::shop::ShopV2;
+use crate::response_types::stats::StatsV2;
+
+mod records;
+
+pub use records::StoreRecord;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    kind TEXT NOT NULL,
+    subject TEXT,
+    fetched_at TEXT NOT NULL,
+    hash TEXT NOT NULL,
+    payload TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS snapshots_kind ON snapshots (kind, subject, fetched_at);
+
+CREATE TABLE IF NOT EXISTS shop_entries (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    offer_id TEXT NOT NULL,
+    section_id TEXT NOT NULL,
+    layout_id TEXT NOT NULL,
+    bundle_name TEXT,
+    regular_price INTEGER NOT NULL,
+    final_price INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS shop_entries_snapshot ON shop_entries (snapshot_id);
+
+CREATE TABLE IF NOT EXISTS shop_entry_items (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    offer_id TEXT NOT NULL,
+    cosmetic_id TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS shop_entry_items_cosmetic ON shop_entry_items (cosmetic_id);
+
+CREATE TABLE IF NOT EXISTS news_motds (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    mode TEXT NOT NULL,
+    motd_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS news_messages (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    mode TEXT NOT NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS playlists (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    playlist_id TEXT NOT NULL,
+    name TEXT,
+    sub_name TEXT,
+    is_limited_time_mode INTEGER NOT NULL,
+    is_tournament INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS aes_keys (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    build TEXT NOT NULL,
+    main_key TEXT NOT NULL,
+    updated TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS aes_dynamic_keys (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    pak_filename TEXT NOT NULL,
+    pak_guid TEXT NOT NULL,
+    key TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS new_cosmetics (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    build TEXT NOT NULL,
+    cosmetic_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    type TEXT NOT NULL,
+    rarity TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS stats (
+    snapshot_id INTEGER NOT NULL REFERENCES snapshots (id) ON DELETE CASCADE,
+    account_id TEXT NOT NULL,
+    account_name TEXT NOT NULL,
+    battle_pass_level INTEGER NOT NULL,
+    wins INTEGER,
+    kills INTEGER,
+    deaths INTEGER,
+    matches INTEGER,
+    kd REAL,
+    win_rate REAL,
+    minutes_played INTEGER
+);
+CREATE INDEX IF NOT EXISTS stats_account ON stats (account_id);
+";
+
+#[derive(Debug)]
+pub enum StoreError {
+    Sqlite(rusqlite::Error),
+    Json(serde_json::Error),
+    Timestamp(chrono::ParseError),
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::Sqlite(error) => write!(f, "database error: {error}"),
+            StoreError::Json(error) => write!(f, "invalid snapshot payload: {error}"),
+            StoreError::Timestamp(error) => write!(f, "invalid snapshot timestamp: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StoreError::Sqlite(error) => Some(error),
+            StoreError::Json(error) => Some(error),
+            StoreError::Timestamp(error) => Some(error),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for StoreError {
+    fn from(error: rusqlite::Error) -> Self {
+        StoreError::Sqlite(error)
+    }
+}
+
+impl From<serde_json::Error> for StoreError {
+    fn from(error: serde_json::Error) -> Self {
+        StoreError::Json(error)
+    }
+}
+
+impl From<chrono::ParseError> for StoreError {
+    fn from(error: chrono::ParseError) -> Self {
+        StoreError::Timestamp(error)
+    }
+}
+
+/// A stored response together with the time it was fetched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot<T> {
+    pub id: i64,
+    pub fetched_at: DateTime<Utc>,
+    pub data: T,
+}
+
+/// A SQLite database of API snapshots.
+pub struct SnapshotStore {
+    connection: Connection,
+}
+
+impl SnapshotStore {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        //! Open or create a snapshot database at `path`.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::storage::SnapshotStore;
+        //!
+        //! let path = std::env::temp_dir().join("fortnite-api-snapshots.db");
+        //! let store = SnapshotStore::open(&path);
+        //! assert!(store.is_ok());
+        //! ```
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self, StoreError> {
+        //! Create a snapshot database that only lives in memory.
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(connection: Connection) -> Result<Self, StoreError> {
+        connection.pragma_update(None, "foreign_keys", true)?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(SnapshotStore { connection })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        //! The underlying connection, for queries against the normalized tables.
+        &self.connection
+    }
+
+    pub fn insert<T: StoreRecord>(
+        &mut self,
+        record: &T,
+        fetched_at: DateTime<Utc>,
+    ) -> Result<i64, StoreError> {
+        //! Store a response fetched at `fetched_at`.
+        //!
+        //! ## Parameters
+        //!
+        //! - `record`: The response to store.
+        //! - `fetched_at`: When the response was fetched.
+        //!
+        //! ## Returns
+        //!
+        //! The id of the new snapshot.
+        //!
+        //! ## Example
+        //!
+        //! ```rust no_run
+        //! use fortnite_api::storage::SnapshotStore;
+        //!
+        //! # #[tokio::main]
+        //! # async fn main() {
+        //! let http_client = reqwest::Client::new();
+        //! let shop = fortnite_api::get_shop_br_v2(&http_client, None).await.unwrap();
+        //!
+        //! let mut store = SnapshotStore::open("snapshots.db").unwrap();
+        //! let id = store.insert(&shop, chrono::Utc::now());
+        //! assert!(id.is_ok());
+        //! # }
+        //! ```
+        let payload = serde_json::to_string(record)?;
+        let hash = record_hash(record, &payload);
+        self.insert_payload(record, fetched_at, &hash, &payload)
+    }
+
+    pub fn insert_if_changed<T: StoreRecord>(
+        &mut self,
+        record: &T,
+        fetched_at: DateTime<Utc>,
+    ) -> Result<Option<i64>, StoreError> {
+        //! Store a response unless the latest snapshot of the same kind and subject has the
+        //! same content hash. Useful when polling an endpoint.
+        //!
+        //! ## Returns
+        //!
+        //! The id of the new snapshot, or `None` if nothing changed.
+        let payload = serde_json::to_string(record)?;
+        let hash = record_hash(record, &payload);
+        let latest_hash: Option<String> = self
+            .connection
+            .query_row(
+                "SELECT hash FROM snapshots WHERE kind = ?1 AND subject IS ?2
+                 ORDER BY fetched_at DESC, id DESC LIMIT 1",
+                params![T::KIND, record.subject()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if latest_hash.as_deref() == Some(hash.as_str()) {
+            return Ok(None);
+        }
+        self.insert_payload(record, fetched_at, &hash, &payload)
+            .map(Some)
+    }
+
+    fn insert_payload<T: StoreRecord>(
+        &mut self,
+        record: &T,
+        fetched_at: DateTime<Utc>,
+        hash: &str,
+        payload: &str,
+    ) -> Result<i64, StoreError> {
+        let transaction = self.connection.transaction()?;
+        transaction.execute(
+            "INSERT INTO snapshots (kind, subject, fetched_at, hash, payload)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                T::KIND,
+                record.subject(),
+                format_timestamp(fetched_at),
+                hash,
+                payload
+            ],
+        )?;
+        let id = transaction.last_insert_rowid();
+        record.insert_rows(&transaction, id)?;
+        transaction.commit()?;
+        Ok(id)
+    }
+
+    pub fn latest<T: StoreRecord>(&self) -> Result<Option<Snapshot<T>>, StoreError> {
+        //! The most recently fetched snapshot of a kind. For kinds with a subject this is the
+        //! latest snapshot of any subject, see [`SnapshotStore::latest_stats`] for the stats
+        //! of one account.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::response_types::shop::ShopV2;
+        //! use fortnite_api::storage::SnapshotStore;
+        //!
+        //! let store = SnapshotStore::open_in_memory().unwrap();
+        //! let shop = store.latest::<ShopV2>().unwrap();
+        //! assert!(shop.is_none());
+        //! ```
+        let mut snapshots = self.query(
+            "SELECT id, fetched_at, payload FROM snapshots WHERE kind = ?1
+             ORDER BY fetched_at DESC, id DESC LIMIT 1",
+            params![T::KIND],
+        )?;
+        Ok(snapshots.pop())
+    }
+
+    pub fn snapshots<T: StoreRecord>(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<Snapshot<T>>, StoreError> {
+        //! All snapshots of a kind fetched within `from..to`, oldest first.
+        //!
+        //! ## Parameters
+        //!
+        //! - `from`: Inclusive lower bound of the fetch time.
+        //! - `to`: Exclusive upper bound of the fetch time.
+        self.query(
+            "SELECT id, fetched_at, payload FROM snapshots
+             WHERE kind = ?1 AND (?2 IS NULL OR fetched_at >= ?2) AND (?3 IS NULL OR fetched_at < ?3)
+             ORDER BY fetched_at, id",
+            params![
+                T::KIND,
+                from.map(format_timestamp),
+                to.map(format_timestamp)
+            ],
+        )
+    }
+
+    pub fn shops_containing(&self, cosmetic_id: &str) -> Result<Vec<Snapshot<ShopV2>>, StoreError> {
+        //! All stored shops that offered the cosmetic `cosmetic_id`, oldest first.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::storage::SnapshotStore;
+        //!
+        //! let store = SnapshotStore::open_in_memory().unwrap();
+        //! let shops = store.shops_containing("CID_029_Athena_Commando_F_Halloween");
+        //! assert!(shops.unwrap().is_empty());
+        //! ```
+        self.query(
+            "SELECT id, fetched_at, payload FROM snapshots
+             WHERE kind = ?1 AND id IN (
+                 SELECT snapshot_id FROM shop_entry_items WHERE cosmetic_id = ?2 COLLATE NOCASE
+             )
+             ORDER BY fetched_at, id",
+            params![ShopV2::KIND, cosmetic_id],
+        )
+    }
+
+    pub fn stats_history(&self, account_id: &str) -> Result<Vec<Snapshot<StatsV2>>, StoreError> {
+        //! All stored stats of the account `account_id`, oldest first.
+        self.query(
+            "SELECT id, fetched_at, payload FROM snapshots
+             WHERE kind = ?1 AND subject = ?2
+             ORDER BY fetched_at, id",
+            params![StatsV2::KIND, account_id],
+        )
+    }
+
+    pub fn latest_stats(&self, account_id: &str) -> Result<Option<Snapshot<StatsV2>>, StoreError> {
+        //! The most recently fetched stats of the account `account_id`.
+        //!
+        //! ## Example
+        //!
+        //! ```rust
+        //! use fortnite_api::storage::SnapshotStore;
+        //!
+        //! let store = SnapshotStore::open_in_memory().unwrap();
+        //! let stats = store.latest_stats("4735ce9132924caf8a5b17789b40f79c");
+        //! assert!(stats.unwrap().is_none());
+        //! ```
+        let mut snapshots = self.query(
+            "SELECT id, fetched_at, payload FROM snapshots WHERE kind = ?1 AND subject = ?2
+             ORDER BY fetched_at DESC, id DESC LIMIT 1",
+            params![StatsV2::KIND, account_id],
+        )?;
+        Ok(snapshots.pop())
+    }
+
+    pub fn delete_before<T: StoreRecord>(
+        &mut self,
+        before: DateTime<Utc>,
+    ) -> Result<usize, StoreError> {
+        //! Delete all snapshots of a kind fetched before `before`.
+        //!
+        //! ## Returns
+        //!
+        //! The number of deleted snapshots.
+        Ok(self.connection.execute(
+            "DELETE FROM snapshots WHERE kind = ?1 AND fetched_at < ?2",
+            params![T::KIND, format_timestamp(before)],
+        )?)
+    }
+
+    fn query<T: StoreRecord>(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<Snapshot<T>>, StoreError> {
+        let mut statement = self.connection.prepare(sql)?;
+        let rows = statement.query_map(params, |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+        let mut snapshots = Vec::new();
+        for row in rows {
+            let (id, fetched_at, payload) = row?;
+            snapshots.push(Snapshot {
+                id,
+                fetched_at: DateTime::parse_from_rfc3339(&fetched_at)?.with_timezone(&Utc),
+                data: serde_json::from_str(&payload)?,
+            });
+        }
+        Ok(snapshots)
+    }
+}
+
+/// Fixed-width timestamps so that text comparison in SQL matches chronological order.
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn record_hash<T: StoreRecord>(record: &T, payload: &str) -> String {
+    record.hash().unwrap_or_else(|| {
+        Sha256::digest(payload.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    })
+}
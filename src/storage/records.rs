@@ -0,0 +1,203 @@
+use rusqlite::{params, Transaction};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::response_types::aes::AesV2;
+use crate::response_types::cosmetics::CosmeticsNewV2;
+use crate::response_types::news::{News, NewsV2};
+use crate::response_types::playlists::PlaylistsV1;
+use crate::response_types::shop::ShopV2;
+use crate::response_types::stats::StatsV2;
+
+/// A response that can be stored in a [`SnapshotStore`](crate::storage::SnapshotStore).
+pub trait StoreRecord: Serialize + DeserializeOwned {
+    /// The value of the `kind` column of this response's snapshots.
+    const KIND: &'static str;
+
+    /// What the snapshot is about when a kind covers several independent series,
+    /// e.g. the account of a stats response.
+    fn subject(&self) -> Option<String> {
+        None
+    }
+
+    /// A content hash provided by the API. Defaults to a SHA-256 of the JSON payload.
+    fn hash(&self) -> Option<String> {
+        None
+    }
+
+    /// Insert the normalized rows of this response for the snapshot `snapshot_id`.
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()>;
+}
+
+impl StoreRecord for ShopV2 {
+    const KIND: &'static str = "shop";
+
+    fn hash(&self) -> Option<String> {
+        Some(self.hash.clone())
+    }
+
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()> {
+        let mut entries = transaction.prepare(
+            "INSERT INTO shop_entries
+             (snapshot_id, offer_id, section_id, layout_id, bundle_name, regular_price, final_price)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )?;
+        let mut items = transaction.prepare(
+            "INSERT INTO shop_entry_items (snapshot_id, offer_id, cosmetic_id) VALUES (?1, ?2, ?3)",
+        )?;
+        for entry in &self.featured.entries {
+            entries.execute(params![
+                snapshot_id,
+                entry.offer_id,
+                entry.section_id,
+                entry.layout.id,
+                entry.bundle.as_ref().map(|bundle| &bundle.name),
+                entry.regular_price as i64,
+                entry.final_price as i64,
+            ])?;
+            for item in &entry.items {
+                items.execute(params![snapshot_id, entry.offer_id, item.id])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StoreRecord for NewsV2 {
+    const KIND: &'static str = "news";
+
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()> {
+        let mut motds = transaction.prepare(
+            "INSERT INTO news_motds (snapshot_id, mode, motd_id, title, body)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        let mut messages = transaction.prepare(
+            "INSERT INTO news_messages (snapshot_id, mode, title, body) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        for (mode, news) in [
+            ("br", &self.br),
+            ("stw", &self.stw),
+            ("creative", &self.creative),
+        ] {
+            let Some(News::NewsContent(content)) = news else {
+                continue;
+            };
+            for motd in content.motds.iter().flatten() {
+                motds.execute(params![snapshot_id, mode, motd.id, motd.title, motd.body])?;
+            }
+            for message in content.messages.iter().flatten() {
+                messages.execute(params![snapshot_id, mode, message.title, message.body])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StoreRecord for PlaylistsV1 {
+    const KIND: &'static str = "playlists";
+
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()> {
+        let mut playlists = transaction.prepare(
+            "INSERT INTO playlists
+             (snapshot_id, playlist_id, name, sub_name, is_limited_time_mode, is_tournament)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for playlist in self {
+            playlists.execute(params![
+                snapshot_id,
+                playlist.id,
+                playlist.name,
+                playlist.sub_name,
+                playlist.is_limited_time_mode,
+                playlist.is_tournament,
+            ])?;
+        }
+        Ok(())
+    }
+}
+
+impl StoreRecord for AesV2 {
+    const KIND: &'static str = "aes";
+
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()> {
+        transaction.execute(
+            "INSERT INTO aes_keys (snapshot_id, build, main_key, updated) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                snapshot_id,
+                self.build,
+                self.main_key.to_string(),
+                self.updated.to_rfc3339()
+            ],
+        )?;
+        let mut keys = transaction.prepare(
+            "INSERT INTO aes_dynamic_keys (snapshot_id, pak_filename, pak_guid, key)
+             VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        for key in &self.dynamic_keys {
+            keys.execute(params![
+                snapshot_id,
+                key.pak_filename,
+                key.pak_guid.to_string(),
+                key.key.to_string()
+            ])?;
+        }
+        Ok(())
+    }
+}
+
+impl StoreRecord for CosmeticsNewV2 {
+    const KIND: &'static str = "new_cosmetics";
+
+    fn hash(&self) -> Option<String> {
+        Some(self.hash.clone())
+    }
+
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()> {
+        let mut cosmetics = transaction.prepare(
+            "INSERT INTO new_cosmetics (snapshot_id, build, cosmetic_id, name, type, rarity)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )?;
+        for item in &self.items {
+            cosmetics.execute(params![
+                snapshot_id,
+                self.build,
+                item.id,
+                item.name,
+                item.r#type.value,
+                item.rarity.value,
+            ])?;
+        }
+        Ok(())
+    }
+}
+
+impl StoreRecord for StatsV2 {
+    const KIND: &'static str = "stats";
+
+    fn subject(&self) -> Option<String> {
+        Some(self.account.id.clone())
+    }
+
+    fn insert_rows(&self, transaction: &Transaction<'_>, snapshot_id: i64) -> rusqlite::Result<()> {
+        let overall = self.stats.all.as_ref().map(|all| &all.overall);
+        transaction.execute(
+            "INSERT INTO stats (snapshot_id, account_id, account_name, battle_pass_level, wins,
+             kills, deaths, matches, kd, win_rate, minutes_played)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                snapshot_id,
+                self.account.id,
+                self.account.name,
+                self.battle_pass.level,
+                overall.map(|overall| overall.wins),
+                overall.map(|overall| overall.kills),
+                overall.map(|overall| overall.deaths),
+                overall.map(|overall| overall.matches),
+                overall.map(|overall| overall.kd),
+                overall.map(|overall| overall.win_rate),
+                overall.map(|overall| overall.minutes_played as i64),
+            ],
+        )?;
+        Ok(())
+    }
+}
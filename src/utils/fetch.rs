@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use reqwest::{RequestBuilder, Url};
+use reqwest::{RequestBuilder, Response, Url};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +26,34 @@ pub(crate) async fn fetch_endpoint<D: DeserializeOwned>(
     raw_result.json::<APIData<_>>().await.map(|d| d.data)
 }
 
+/// Like [`fetch_endpoint`], but error responses fail with their HTTP status.
+pub(crate) async fn fetch_endpoint_checked<D: DeserializeOwned>(
+    http_client: &reqwest::Client,
+    uri: Url,
+    method: &str,
+    body: &str,
+    headers: &HashMap<String, String>,
+) -> reqwest::Result<D> {
+    let raw_result = build_request(uri, http_client, method, body, headers)?
+        .send()
+        .await?
+        .error_for_status()?;
+    raw_result.json::<APIData<_>>().await.map(|d| d.data)
+}
+
+/// Send a request without interpreting the response, e.g. to read its headers.
+pub(crate) async fn send_request(
+    http_client: &reqwest::Client,
+    uri: Url,
+    method: &str,
+    body: &str,
+    headers: &HashMap<String, String>,
+) -> reqwest::Result<Response> {
+    build_request(uri, http_client, method, body, headers)?
+        .send()
+        .await
+}
+
 fn build_request(
     uri: Url,
     http_client: &reqwest::Client,